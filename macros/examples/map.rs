@@ -1,41 +1,30 @@
-#![feature(proc_macro_hygiene)]
+#![feature(async_await, await_macro, futures_api, proc_macro_hygiene)]
 
-extern crate futures;
-extern crate reql;
-extern crate reql_macros;
-#[macro_use]
-extern crate serde_json;
-
-use futures::Stream;
-use reql_macros::args;
-use reql::{Config, Client, Document, Run};
+use {reql::r, reql_macros::args, serde_json::json};
 
 fn main() -> reql::Result<()> {
-    // Create a new ReQL client
-    let r = Client::new();
-
-    // Create a connection pool
-    let conn = r.connect(Config::default())?;
-
-    // Run the query
-    let sequence1 = json!([100, 200, 300, 400]);
-    let sequence2 = json!([10, 20, 30, 40]);
-    let sequence3 = json!([1, 2, 3, 4]);
-
-    let sum = r.map(args!(sequence1, sequence2, sequence3, |val1, val2, val3| {
-        val1.add(val2).add(val3)
-    }))
-    .run::<[i32; 4]>(conn)?;
-
-    // Process results
-    match sum.wait().next().unwrap()? {
-        Some(Document::Expected(sum)) => {
-            println!("{:?}", sum);
-        }
-        res => {
-            println!("unexpected response from DB: {:?}", res);
-        }
-    }
-
-    Ok(())
+    futures::executor::block_on(
+        async {
+            // Create a new connection
+            let conn = await!(r.connect(()))?;
+
+            let sequence1 = json!([100, 200, 300, 400]);
+            let sequence2 = json!([10, 20, 30, 40]);
+            let sequence3 = json!([1, 2, 3, 4]);
+
+            // Run the query
+            let query = r.expr(sequence1).map(args!(sequence2, sequence3, |val1, val2, val3| {
+                val1.add(val2).add(val3)
+            }));
+            let resp = await!(query.run::<_, [i32; 4]>(&conn))?;
+
+            // Process results
+            match resp.first() {
+                Some(sum) => println!("{:?}", sum),
+                None => println!("got no response from the server"),
+            }
+
+            Ok(())
+        },
+    )
 }
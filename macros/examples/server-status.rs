@@ -1,40 +1,32 @@
-#![feature(proc_macro_hygiene)]
+#![feature(async_await, await_macro, futures_api, proc_macro_hygiene)]
 
-extern crate futures;
-extern crate reql;
-extern crate reql_macros;
-extern crate reql_types;
-
-use reql::{Config, Client, Document, Run};
-use reql_types::{Change, ServerStatus};
-use reql_macros::args;
-use futures::Stream;
+use {
+    futures::prelude::*,
+    reql::r,
+    reql_macros::args,
+    reql_types::{Change, ServerStatus},
+};
 
 fn main() -> reql::Result<()> {
-    // Create a new ReQL client
-    let r = Client::new();
-
-    // Create a connection pool
-    let conn = r.connect(Config::default())?;
+    futures::executor::block_on(
+        async {
+            // Create a new connection
+            let conn = await!(r.connect(()))?;
 
-    // Run the query
-    let stati = r.db("rethinkdb")
-        .table("server_status")
-        .changes()
-        .with_args(args!({include_initial: true}))
-        .run::<Change<ServerStatus, ServerStatus>>(conn)?;
+            // Run the query
+            let mut stati = r
+                .db("rethinkdb")
+                .table("server_status")
+                .changes(args!({ include_initial: true }))
+                .run::<_, Change<ServerStatus, ServerStatus>>(&conn)
+                .into_changes();
 
-    // Process results
-    for res in stati.wait() {
-        match res {
-            Ok(Some(Document::Expected(change))) => {
-                println!("{:?}", change);
-            }
-            res => {
-                println!("unexpected response from server: {:?}", res);
+            // Process results
+            while let Some(change) = await!(stati.next()) {
+                println!("{:?}", change?);
             }
-        }
-    }
 
-    Ok(())
+            Ok(())
+        },
+    )
 }
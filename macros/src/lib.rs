@@ -1,30 +1,27 @@
 #![recursion_limit = "128"]
-#![feature(proc_macro_diagnostic)]
 
 //! This crate provides macros for making ReQL types more pleasant to work with.
 //! Currently, it only exposes the `args` macro but it's possible that more will
 //! be added in the future.
 //!
-//! In this crate we make use of a couple nightly features, specifically
-//! `proc_macro` and `proc_macro_non_items` so you will need to use the latest nightly
-//! compiler in order to use the macros here. Add the following to your crate:-
+//! The macros here are function-like procedural macros used in expression
+//! position so you will need to use the latest nightly compiler in order to use
+//! them. Add the following to your crate:-
 //!
 //! ```rust,ignore
-//! #![feature(proc_macro, proc_macro_hygiene)]
+//! #![feature(proc_macro_hygiene)]
 //! ```
 
-#[macro_use]
-extern crate quote;
-#[macro_use]
-extern crate syn;
 extern crate proc_macro;
-extern crate proc_macro2;
 
 mod args;
 mod parser;
 
-use proc_macro::TokenStream;
-use syn::punctuated::Punctuated;
+use {
+    proc_macro::TokenStream,
+    quote::quote,
+    syn::{punctuated::Punctuated, Token},
+};
 
 #[derive(Debug, Clone)]
 struct KvPair(syn::Ident, syn::Expr);
@@ -32,9 +29,6 @@ struct KvPair(syn::Ident, syn::Expr);
 #[derive(Debug, Clone)]
 struct Opt(Punctuated<KvPair, Token![,]>);
 
-#[derive(Debug, Clone)]
-struct List(Punctuated<syn::Expr, Token![,]>);
-
 #[derive(Debug, Clone)]
 struct Args(Punctuated<Arg, Token![,]>);
 
@@ -48,32 +42,47 @@ enum Arg {
     Bad(ToComma),
 }
 
-/// Splice an array of arguments into another term
+/// Splice a number of arguments into a command
 ///
-/// A macro that’s used to splice a number of arguments into another term. This is
-/// useful when you want to call a variadic term such as `branch` with a set of arguments produced at
-/// runtime.
+/// A macro that’s used to splice a number of arguments into any command that
+/// takes arguments. This is useful when you want to call a variadic command
+/// such as `branch` or `order_by` with more arguments than its tuples allow,
+/// or with arguments that can't be put in a JSON array, such as queries.
+///
+/// Options are passed as `{ key: value }` objects and their values can be
+/// queries too. Closures don't need type annotations for their arguments,
+/// they are all `Client`s.
 ///
 /// # Example
 ///
 /// If `x` is greater than `5` return `big`, otherwise return `small`.
 ///
 /// ```rust,ignore
-/// r.branch(args!(r.expr(x).gt(5), "big", "small"));
+/// r.branch(args!(r.expr(x).gt(5), "big", "small"))
+/// ```
+///
+/// # Example
+///
+/// Order heroes by their number of victories first, then by name.
+///
+/// ```rust,ignore
+/// r.table("marvel").order_by(args!(r.desc("victories"), r.asc("name")))
+/// ```
+///
+/// # Example
+///
+/// Get the posts written by `bob`, including those without an author.
+///
+/// ```rust,ignore
+/// r.table("posts").filter(args!(|post| post.get_field("author").eq("bob"), { default: true }))
 /// ```
 #[proc_macro]
 pub fn args(input: TokenStream) -> TokenStream {
-    let body = if input.is_empty() {
-        quote! { Term::new() }
+    let expanded = if input.is_empty() {
+        quote!(reql::cmd::Args::new())
     } else {
         args::process(input)
     };
 
-    let expanded = quote!({
-        #[allow(unused_imports)]
-        use reql::{Term, IntoArg, Arg};
-        #body
-    });
-
     expanded.into()
 }
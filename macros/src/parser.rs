@@ -1,108 +1,74 @@
-use {KvPair, Opt, List, Arg, Args, ToComma};
-use syn::synom::Synom;
-use syn::{Ident, Expr};
-use syn::punctuated::Punctuated;
-use proc_macro2::{TokenTree, TokenStream};
-use syn::buffer::Cursor;
-use syn::synom::PResult;
-use std::iter::FromIterator;
+use {
+    crate::{Arg, Args, KvPair, Opt, ToComma},
+    proc_macro2::{TokenStream, TokenTree},
+    syn::{
+        braced,
+        parse::{Parse, ParseStream, Result},
+        punctuated::Punctuated,
+        token, Expr, Ident, Token,
+    },
+};
 
-impl Synom for KvPair {
-    named!(parse -> Self, do_parse!(
-            key: syn!(Ident) >>
-            punct!(:) >>
-            val: syn!(Expr) >>
-            (KvPair(key, val))
-    ));
-
-    fn description() -> Option<&'static str> {
-        Some("key value pair")
-    }
-}
-
-impl Synom for Opt {
-    named!(parse -> Self, do_parse!(
-            body: braces!(call!(Punctuated::parse_terminated_nonempty)) >>
-            (Opt(body.1))
-    ));
-
-    fn description() -> Option<&'static str> {
-        Some("opt")
+impl Parse for KvPair {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key: Ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let val: Expr = input.parse()?;
+        Ok(KvPair(key, val))
     }
 }
 
-impl Synom for List {
-    named!(parse -> Self, do_parse!(
-            body: brackets!(call!(Punctuated::parse_terminated_nonempty)) >>
-            (List(body.1))
-    ));
-
-    fn description() -> Option<&'static str> {
-        Some("list")
+impl Parse for Opt {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let body;
+        braced!(body in input);
+        let pairs = Punctuated::parse_terminated(&body)?;
+        if pairs.is_empty() {
+            return Err(body.error("expected at least one option"));
+        }
+        Ok(Opt(pairs))
     }
 }
 
-impl Synom for Args {
-    named!(parse -> Self, do_parse!(
-            body: call!(Punctuated::parse_terminated_nonempty) >>
-            (Args(body))
-    ));
-
-    fn description() -> Option<&'static str> {
-        Some("arguments")
+impl Parse for Args {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let args = Punctuated::parse_terminated(input)?;
+        Ok(Args(args))
     }
 }
 
-impl Synom for Arg {
-    fn parse(input: Cursor) -> PResult<Self> {
-        // is it an expression?
-        if let Ok((expr, cursor)) = Expr::parse(input) {
-            if cursor.eof() {
-                return Ok((Arg::Expr(expr), cursor));
-            }
-            if let Some((tree, _)) = cursor.token_tree() {
-                if let TokenTree::Punct(p) = tree {
-                    if p.as_char() == ',' {
-                        return Ok((Arg::Expr(expr), cursor));
-                    }
-                }
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // is it an option? this has to come first because `{ key: value }`
+        // is also a valid block expression
+        if input.peek(token::Brace) {
+            let fork = input.fork();
+            if fork.parse::<Opt>().is_ok() && at_end(&fork) {
+                return input.parse().map(Arg::Opt);
             }
         }
-        // how about an option?
-        if let Ok((opt, cursor)) = Opt::parse(input) {
-            return Ok((Arg::Opt(opt), cursor));
+        // how about an expression?
+        let fork = input.fork();
+        if fork.parse::<Expr>().is_ok() && at_end(&fork) {
+            return input.parse().map(Arg::Expr);
         }
         // it must be a bad argument then
-        if let Ok((to_comma, cursor)) = ToComma::parse(input) {
-            return Ok((Arg::Bad(to_comma), cursor));
-        }
-        reject!("unexpected input",)
-    }
-
-    fn description() -> Option<&'static str> {
-        Some("argument")
+        input.parse().map(Arg::Bad)
     }
 }
 
-impl Synom for ToComma {
-    fn parse(mut rest: Cursor) -> PResult<Self> {
-        let mut tokens = Vec::new();
-        while let Some((tree, cursor)) = rest.token_tree() {
-            if let TokenTree::Punct(p) = tree.clone() {
-                if p.as_char() == ',' {
-                    if !tokens.is_empty() {
-                        break;
-                    }
-                }
-            }
-            rest = cursor;
-            tokens.push(tree);
+impl Parse for ToComma {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut tokens = TokenStream::new();
+        while !at_end(input) {
+            let tree: TokenTree = input.parse()?;
+            tokens.extend(Some(tree));
         }
-        let stream = TokenStream::from_iter(tokens);
-        Ok((ToComma(stream), rest))
-    }
-    
-    fn description() -> Option<&'static str> {
-        Some("token stream to comma")
+        Ok(ToComma(tokens))
     }
 }
+
+// Whether we have reached the end of the current argument
+fn at_end(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(Token![,])
+}
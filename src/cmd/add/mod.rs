@@ -0,0 +1,67 @@
+mod arg;
+
+use {
+    crate::{cmd::IntoArg, Client},
+    std::ops::Add,
+};
+
+pub use arg::Arg;
+
+impl Client {
+    /// Sum two or more numbers, or concatenate two or more strings or arrays
+    ///
+    /// The `+` operator can be used as well. To add more than one value, pass
+    /// them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// It's as easy as 2 + 2 = 4.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(2).add(2)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Concatenate strings.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr("foo") + "bar"
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [sub]
+    /// - [mul]
+    /// - [div]
+    /// - [mod_]
+    ///
+    /// [sub]: struct.Client.html#method.sub
+    /// [mul]: struct.Client.html#method.mul
+    /// [div]: struct.Client.html#method.div
+    /// [mod_]: struct.Client.html#method.mod_
+    /// [r.args]: struct.r.html#method.args
+    pub fn add<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 24, arg.into())
+    }
+}
+
+impl<T> Add<T> for Client
+where
+    T: IntoArg,
+{
+    type Output = Client;
+
+    fn add(self, rhs: T) -> Client {
+        Client::add(&self, rhs)
+    }
+}
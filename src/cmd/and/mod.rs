@@ -0,0 +1,76 @@
+mod arg;
+
+use {
+    crate::{cmd::IntoArg, Client},
+    std::ops::BitAnd,
+};
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compute the logical "and" of one or more values
+    ///
+    /// The `&` operator can be used as well. To compute the "and" of more than two
+    /// values, pass them using [r.args]. Note that, unlike the Rust operator `&&`,
+    /// both sides are sent to the server and evaluated there.
+    ///
+    /// ## Example
+    ///
+    /// Return whether both `a` and `b` evaluate to true.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(true).and(false)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Use the operator instead.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(true) & false
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [or]
+    /// - [not]
+    ///
+    /// [or]: struct.Client.html#method.or
+    /// [not]: struct.Client.html#method.not
+    /// [r.args]: struct.r.html#method.args
+    pub fn and<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 67, arg.into())
+    }
+}
+
+impl<T> BitAnd<T> for Client
+where
+    T: IntoArg,
+{
+    type Output = Client;
+
+    fn bitand(self, rhs: T) -> Client {
+        Client::and(&self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn operators_build_the_same_terms_as_methods() {
+        let query = (r.expr(2) + 3).gt(4) & !r.expr(false);
+        let expected = r#"[67,[[21,[[24,[2,3]],4]],[23,[false]]]]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,36 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Append a value to an array
+    ///
+    /// ## Example
+    ///
+    /// Retrieve Iron Man's equipment list with the addition of some new boots.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").get_field("equipment").append("new_boots")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [prepend]
+    /// - [insert_at]
+    /// - [set_insert]
+    ///
+    /// [prepend]: struct.Client.html#method.prepend
+    /// [insert_at]: struct.Client.html#method.insert_at
+    /// [set_insert]: struct.Client.html#method.set_insert
+    pub fn append<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 29, arg.into())
+    }
+}
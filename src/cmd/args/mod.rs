@@ -0,0 +1,31 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Splice an array of arguments into another term
+    ///
+    /// This is useful when you want to pass a variable number of arguments
+    /// to commands such as [get_all].
+    ///
+    /// ## Example
+    ///
+    /// Get Alice and Bob from the table `people`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("people").get_all(r.args(["Alice", "Bob"]))
+    /// # ;
+    /// ```
+    ///
+    /// [get_all]: struct.Client.html#method.get_all
+    pub fn args<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 154, arg.into())
+    }
+}
@@ -0,0 +1,34 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Specify ascending order for [order_by]
+    ///
+    /// ## Example
+    ///
+    /// Sort heroes by their names in ascending order.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").order_by(r.asc("name"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [desc]
+    /// - [order_by]
+    ///
+    /// [desc]: struct.r.html#method.desc
+    /// [order_by]: struct.Client.html#method.order_by
+    pub fn asc<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 73, arg.into())
+    }
+}
@@ -0,0 +1,54 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Average all the elements of a sequence
+    ///
+    /// If called with a field name, average all the values of that field in the
+    /// sequence, skipping elements of the sequence that lack that field. If called
+    /// with a function, call that function on every element of the sequence and
+    /// average the results, skipping elements of the sequence where that function
+    /// returns `null` or a non-existence error. Produces an error when called on an
+    /// empty sequence.
+    ///
+    /// ## Example
+    ///
+    /// What's the average of 3, 5 and 7?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr([3, 5, 7]).avg(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// What's the average number of points scored in a game?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("games").avg("points")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [count]
+    /// - [sum]
+    /// - [group]
+    ///
+    /// [count]: struct.Client.html#method.count
+    /// [sum]: struct.Client.html#method.sum
+    /// [group]: struct.Client.html#method.group
+    pub fn avg<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 146, arg.into())
+    }
+}
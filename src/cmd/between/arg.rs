@@ -0,0 +1,56 @@
+use {
+    super::Opts,
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<L, U> From<(L, U)> for Arg
+where
+    L: IntoArg,
+    U: IntoArg,
+{
+    fn from((lower, upper): (L, U)) -> Self {
+        Arg {
+            arg: join_args(&[&lower.into_arg(), &upper.into_arg()]),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<'a, L, U> From<(L, U, Opts<'a>)> for Arg
+where
+    L: IntoArg,
+    U: IntoArg,
+{
+    fn from((lower, upper, opts): (L, U, Opts<'a>)) -> Self {
+        Arg {
+            arg: join_args(&[&lower.into_arg(), &upper.into_arg()]),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
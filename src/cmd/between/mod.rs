@@ -0,0 +1,98 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Get all documents between two keys
+    ///
+    /// Accepts a `(lower, upper)` tuple, optionally followed by [Opts]. By
+    /// default the lower key is included in the range and the upper key is
+    /// not. Use [r.minval] and [r.maxval] for ranges that are unbounded on
+    /// either side.
+    ///
+    /// ## Example
+    ///
+    /// Find all users with primary key >= 10 and < 20 (a normal half-open
+    /// interval).
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").between((10, 20))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Find all users with primary key >= 10 and <= 20 (an interval closed
+    /// on both sides).
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::between::{Bound, Opts}};
+    /// #
+    /// let opts = Opts::builder().right_bound(Bound::Closed).build();
+    /// r.table("marvel").between((10, 20, opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Find all users with primary key < 20.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").between((r.minval(), 20))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Find all users with a `code_name` between `a` and `m`.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::between::Opts};
+    /// #
+    /// let opts = Opts::builder().index("code_name").build();
+    /// r.table("marvel").between(("a", "m", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get]
+    /// - [get_all]
+    ///
+    /// [Opts]: cmd/between/struct.Opts.html
+    /// [r.minval]: struct.r.html#method.minval
+    /// [r.maxval]: struct.r.html#method.maxval
+    /// [get]: struct.Client.html#method.get
+    /// [get_all]: struct.Client.html#method.get_all
+    pub fn between<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 182, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::r};
+
+    #[test]
+    fn bounds_and_sentinels_are_serialised() {
+        let opts = Opts::builder()
+            .index("code_name")
+            .right_bound(Bound::Closed)
+            .build();
+        let query = r.table("marvel").between((r.minval(), "m", opts));
+        let expected =
+            r#"[182,[[15,["marvel"]],[180,[]],"m"],{"index":"code_name","right_bound":"closed"}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
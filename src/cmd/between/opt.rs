@@ -0,0 +1,48 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    left_bound: Option<Bound>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    right_bound: Option<Bound>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Bound {
+    Open,
+    Closed,
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// The index to select the range on (default: the primary key)
+    pub fn index(&mut self, index: &'a str) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Whether the lower key is included in the range (default `closed`)
+    pub fn left_bound(&mut self, bound: Bound) -> &mut Self {
+        self.left_bound = Some(bound);
+        self
+    }
+
+    /// Whether the upper key is included in the range (default `open`)
+    pub fn right_bound(&mut self, bound: Bound) -> &mut Self {
+        self.right_bound = Some(bound);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
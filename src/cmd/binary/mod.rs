@@ -0,0 +1,67 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Encapsulate binary data within a query
+    ///
+    /// The argument is either a [Binary] value or a string, in which case
+    /// its UTF-8 bytes become the binary data. A [Binary] value can also be
+    /// used directly wherever a datum is expected, for example inside a
+    /// document being inserted. Binary data returned by the server can be
+    /// deserialised into a [Binary] as well, unless the `binary_format`
+    /// option of [run] is set to `raw`.
+    ///
+    /// ## Example
+    ///
+    /// Save an avatar image to an existing user record.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use reql_types::Binary;
+    /// # use serde_json::json;
+    /// #
+    /// let avatar = Binary::from(vec![0x89, 0x50, 0x4e, 0x47]);
+    /// r.table("users").get(100).update(json!({"avatar": avatar}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Convert a string into binary data.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.binary("Hello, world!")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [coerce_to]
+    ///
+    /// [Binary]: https://docs.rs/reql-types/*/reql_types/struct.Binary.html
+    /// [run]: struct.Client.html#method.run
+    /// [coerce_to]: struct.Client.html#method.coerce_to
+    pub fn binary<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 155, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {crate::r, reql_types::Binary};
+
+    #[test]
+    fn binary_is_serialised_as_a_pseudo_type() {
+        let query = r.binary(Binary::from(&b"hello"[..]));
+        let expected = r#"[155,[{"$reql_type$":"BINARY","data":"aGVsbG8="}]]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
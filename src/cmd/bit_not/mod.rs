@@ -0,0 +1,31 @@
+use crate::Client;
+
+impl Client {
+    /// Compute the arithmetic inverse (not) of a number
+    ///
+    /// The number is converted to a 53-bit integer first.
+    ///
+    /// ## Example
+    ///
+    /// Compute ~7.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(7).bit_not()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [bit_and]
+    /// - [bit_or]
+    /// - [bit_xor]
+    ///
+    /// [bit_and]: struct.Client.html#method.bit_and
+    /// [bit_or]: struct.Client.html#method.bit_or
+    /// [bit_xor]: struct.Client.html#method.bit_xor
+    pub fn bit_not(&self) -> Client {
+        Client::new(&self.0, 194, ())
+    }
+}
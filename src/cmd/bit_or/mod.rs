@@ -0,0 +1,40 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compute the arithmetic "or" of one or more values
+    ///
+    /// Both numbers are converted to 53-bit integers first. To compute the "or"
+    /// of more than two values, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// Compute 5 | 3.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(5).bit_or(3)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [bit_and]
+    /// - [bit_xor]
+    /// - [bit_not]
+    ///
+    /// [bit_and]: struct.Client.html#method.bit_and
+    /// [bit_xor]: struct.Client.html#method.bit_xor
+    /// [bit_not]: struct.Client.html#method.bit_not
+    /// [r.args]: struct.r.html#method.args
+    pub fn bit_or<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 192, arg.into())
+    }
+}
@@ -0,0 +1,34 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compute the left arithmetic shift of a number
+    ///
+    /// The sign bit is preserved.
+    ///
+    /// ## Example
+    ///
+    /// Compute 5 << 4.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(5).bit_sal(4)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [bit_sar]
+    ///
+    /// [bit_sar]: struct.Client.html#method.bit_sar
+    pub fn bit_sal<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 195, arg.into())
+    }
+}
@@ -0,0 +1,34 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compute the right arithmetic shift of a number
+    ///
+    /// The sign bit is preserved.
+    ///
+    /// ## Example
+    ///
+    /// Compute 32 >> 3.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(32).bit_sar(3)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [bit_sal]
+    ///
+    /// [bit_sal]: struct.Client.html#method.bit_sal
+    pub fn bit_sar<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 196, arg.into())
+    }
+}
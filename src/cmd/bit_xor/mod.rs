@@ -0,0 +1,40 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compute the arithmetic "exclusive or" of one or more values
+    ///
+    /// Both numbers are converted to 53-bit integers first. To compute the
+    /// "exclusive or" of more than two values, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// Compute 6 ^ 4.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(6).bit_xor(4)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [bit_and]
+    /// - [bit_or]
+    /// - [bit_not]
+    ///
+    /// [bit_and]: struct.Client.html#method.bit_and
+    /// [bit_or]: struct.Client.html#method.bit_or
+    /// [bit_not]: struct.Client.html#method.bit_not
+    /// [r.args]: struct.r.html#method.args
+    pub fn bit_xor<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 193, arg.into())
+    }
+}
@@ -0,0 +1,48 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Get a single field from an object or a single element from a sequence
+    ///
+    /// Works like [get_field] when passed a string and like [nth] when passed a
+    /// number.
+    ///
+    /// ## Example
+    ///
+    /// What was Iron Man's first appearance in a comic?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").bracket("first_appearance")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Get the second element of an array.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr([10, 20, 30]).bracket(1)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get_field]
+    /// - [nth]
+    ///
+    /// [get_field]: struct.Client.html#method.get_field
+    /// [nth]: struct.Client.html#method.nth
+    pub fn bracket<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 170, arg.into())
+    }
+}
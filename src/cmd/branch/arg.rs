@@ -0,0 +1,69 @@
+use {
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<T, V, E> From<(T, V, E)> for Arg
+where
+    T: IntoArg,
+    V: IntoArg,
+    E: IntoArg,
+{
+    fn from((test, value, otherwise): (T, V, E)) -> Self {
+        let args = [
+            &test.into_arg()[..],
+            &value.into_arg(),
+            &otherwise.into_arg(),
+        ];
+        Arg {
+            arg: join_args(&args),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<T, V, E> From<(Vec<(T, V)>, E)> for Arg
+where
+    T: IntoArg,
+    V: IntoArg,
+    E: IntoArg,
+{
+    fn from((pairs, otherwise): (Vec<(T, V)>, E)) -> Self {
+        let mut args = Vec::with_capacity(pairs.len() * 2 + 1);
+        for (test, value) in pairs {
+            args.push(test.into_arg());
+            args.push(value.into_arg());
+        }
+        args.push(otherwise.into_arg());
+        let args: Vec<_> = args.iter().map(|arg| &arg[..]).collect();
+        Arg {
+            arg: join_args(&args),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,75 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Perform a branching conditional equivalent to `if-then-else`
+    ///
+    /// Accepts a `(test, value, otherwise)` tuple. The `branch` command takes
+    /// two or more of these arguments, so to test more than one condition pass
+    /// a vector of `(test, value)` pairs followed by the `otherwise` value.
+    /// The first value whose test is true is returned, or `otherwise` if none
+    /// of them are.
+    ///
+    /// Note that only `false` and `null` count as false; any other value,
+    /// including `0` and the empty string, counts as true.
+    ///
+    /// ## Example
+    ///
+    /// Test the value of `x`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// let x = 10;
+    /// r.branch((r.expr(x).gt(5), "big", "small"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Categorize heroes by victory counts.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("marvel").map(|hero: Client| {
+    ///     let victories = hero.get_field("victories");
+    ///     let pairs = vec![
+    ///         (victories.gt(100), hero.get_field("name") + " is a superhero"),
+    ///         (victories.gt(10), hero.get_field("name") + " is a hero"),
+    ///     ];
+    ///     r.branch((pairs, hero.get_field("name") + " is very nice"))
+    /// })
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.do_]
+    /// - [default]
+    ///
+    /// [r.do_]: struct.r.html#method.do_
+    /// [default]: struct.Client.html#method.default
+    pub fn branch<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 65, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn condition_pairs_are_flattened() {
+        let pairs = vec![(r.expr(true), "a"), (r.expr(false), "b")];
+        let query = r.branch((pairs, "c"));
+        let expected = r#"[65,[true,"a",false,"b","c"]]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
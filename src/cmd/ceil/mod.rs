@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Round a number up to the nearest integer
+    ///
+    /// ## Example
+    ///
+    /// Return the ceiling of 12.345.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(12.345).ceil()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [floor]
+    /// - [round]
+    ///
+    /// [floor]: struct.Client.html#method.floor
+    /// [round]: struct.Client.html#method.round
+    pub fn ceil(&self) -> Client {
+        Client::new(&self.0, 184, ())
+    }
+}
@@ -0,0 +1,38 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Change a value in an array at a given index
+    ///
+    /// Accepts an `(index, value)` tuple. Returns the modified array.
+    ///
+    /// ## Example
+    ///
+    /// Bruce Banner hulks out.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["Iron Man", "Bruce", "Spider-Man"]).change_at((1, "Hulk"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [insert_at]
+    /// - [splice_at]
+    /// - [delete_at]
+    ///
+    /// [insert_at]: struct.Client.html#method.insert_at
+    /// [splice_at]: struct.Client.html#method.splice_at
+    /// [delete_at]: struct.Client.html#method.delete_at
+    pub fn change_at<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 84, arg.into())
+    }
+}
@@ -0,0 +1,52 @@
+use {
+    crate::{cmd::run::Run, Result},
+    futures::{prelude::*, ready, Poll},
+    reql_types::Change,
+    serde::de::DeserializeOwned,
+    std::{collections::VecDeque, pin::Pin, task::LocalWaker},
+};
+
+/// An endless stream of changes returned by [into_changes]
+///
+/// The server sends changes in batches. This stream hands them out one at a
+/// time and keeps asking the server for more for as long as it is polled.
+///
+/// [into_changes]: ../run/struct.Run.html#method.into_changes
+#[derive(Debug)]
+pub struct Changes<'a, O, N> {
+    run: Run<'a, Change<O, N>>,
+    buffer: VecDeque<Change<O, N>>,
+}
+
+impl<'a, O, N> Run<'a, Change<O, N>> {
+    /// Turn the response batches of a changefeed into a stream of individual
+    /// changes
+    pub fn into_changes(self) -> Changes<'a, O, N> {
+        Changes {
+            run: self,
+            buffer: VecDeque::new(),
+        }
+    }
+}
+
+impl<'a, O, N> Stream for Changes<'a, O, N>
+where
+    O: DeserializeOwned + Unpin,
+    N: DeserializeOwned + Unpin,
+{
+    type Item = Result<Change<O, N>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(change) = self.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(change)));
+            }
+            // batches can be empty, in which case we simply ask for the next one
+            match ready!(Pin::new(&mut self.run).poll_next(lw)) {
+                Some(Ok(resp)) => self.buffer.extend(resp.into_value()),
+                Some(Err(error)) => return Poll::Ready(Some(Err(error))),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
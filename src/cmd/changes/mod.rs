@@ -0,0 +1,92 @@
+mod arg;
+mod feed;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use feed::Changes;
+pub use opt::*;
+
+impl Client {
+    /// Turn a query into a changefeed, an infinite stream of objects
+    /// representing changes to the query's results as they occur
+    ///
+    /// A changefeed may return changes to a table or an individual document
+    /// (a "point" changefeed). Commands such as [filter] or [map] may be used
+    /// before the `changes` command to transform or filter the output, and
+    /// many commands that operate on sequences can be chained after it.
+    ///
+    /// Pass `()` to use the default options. Each change can be deserialised
+    /// into a [Change]. Calling [into_changes] on the result of [run] gives a
+    /// stream of those changes which never ends on its own.
+    ///
+    /// ## Example
+    ///
+    /// Subscribe to the changes on a table, sending the kind of each change
+    /// along with it.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::changes::Opts};
+    /// #
+    /// let opts = Opts::builder().include_types(true).build();
+    /// r.table("games").changes(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Print every change made to a table.
+    ///
+    /// ```rust,no_run
+    /// # #![feature(async_await, await_macro, futures_api)]
+    /// # use futures::{executor::block_on, prelude::*};
+    /// # use reql::r;
+    /// # use reql_types::Change;
+    /// # use serde_json::Value;
+    /// # fn main() -> reql::Result<()> {
+    /// # block_on(async {
+    /// let conn = await!(r.connect(()))?;
+    /// let mut feed = r
+    ///     .table("games")
+    ///     .changes(())
+    ///     .run::<_, Change<Value, Value>>(&conn)
+    ///     .into_changes();
+    /// while let Some(change) = await!(feed.next()) {
+    ///     println!("{:?}", change?.new_val);
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [table]
+    ///
+    /// [filter]: struct.Client.html#method.filter
+    /// [map]: struct.Client.html#method.map
+    /// [Change]: https://docs.rs/reql-types/*/reql_types/struct.Change.html
+    /// [into_changes]: cmd/run/struct.Run.html#method.into_changes
+    /// [run]: struct.Client.html#method.run
+    /// [table]: struct.Client.html#method.table
+    pub fn changes<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 152, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::r};
+
+    #[test]
+    fn squash_accepts_seconds() {
+        let opts = Opts::builder().squash(0.5).include_types(true).build();
+        let query = r.table("games").changes(opts);
+        let expected = r#"[152,[[15,["games"]]],{"squash":0.5,"include_types":true}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
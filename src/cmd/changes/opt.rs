@@ -0,0 +1,105 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::{Serialize, Serializer},
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    squash: Option<Squash>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    changefeed_queue_size: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_initial: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_states: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_offsets: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_types: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Squash {
+    Bool(bool),
+    Secs(f64),
+}
+
+impl Opts {
+    make_builder!();
+
+    /// How to combine changes that happen in quick succession
+    ///
+    /// `false` (the default) sends every change, `true` sends them in
+    /// batches as fast as possible, merging changes to the same document,
+    /// and a number of seconds waits that long before sending each batch.
+    pub fn squash<T>(&mut self, squash: T) -> &mut Self
+    where
+        T: Into<Squash>,
+    {
+        self.squash = Some(squash.into());
+        self
+    }
+
+    /// The number of changes the server buffers before dropping them and
+    /// sending an error (default `100000`)
+    pub fn changefeed_queue_size(&mut self, size: usize) -> &mut Self {
+        self.changefeed_queue_size = Some(size);
+        self
+    }
+
+    /// Send the current results before any changes (default `false`)
+    pub fn include_initial(&mut self, include: bool) -> &mut Self {
+        self.include_initial = Some(include);
+        self
+    }
+
+    /// Send status changes about the feed in the `state` field (default
+    /// `false`)
+    pub fn include_states(&mut self, include: bool) -> &mut Self {
+        self.include_states = Some(include);
+        self
+    }
+
+    /// Send the `old_offset` and `new_offset` of changes to an ordered and
+    /// limited query (default `false`)
+    pub fn include_offsets(&mut self, include: bool) -> &mut Self {
+        self.include_offsets = Some(include);
+        self
+    }
+
+    /// Send the kind of each change in the `type` field (default `false`)
+    pub fn include_types(&mut self, include: bool) -> &mut Self {
+        self.include_types = Some(include);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
+
+impl From<bool> for Squash {
+    fn from(squash: bool) -> Self {
+        Squash::Bool(squash)
+    }
+}
+
+impl From<f64> for Squash {
+    fn from(secs: f64) -> Self {
+        Squash::Secs(secs)
+    }
+}
+
+impl Serialize for Squash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Squash::Bool(value) => value.serialize(serializer),
+            Squash::Secs(secs) => secs.serialize(serializer),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use {
+    super::Opts,
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<C, R> From<(C, R)> for Arg
+where
+    C: IntoArg,
+    R: IntoArg,
+{
+    fn from((center, radius): (C, R)) -> Self {
+        Arg {
+            arg: join_args(&[&center.into_arg(), &radius.into_arg()]),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<C, R> From<(C, R, Opts)> for Arg
+where
+    C: IntoArg,
+    R: IntoArg,
+{
+    fn from((center, radius, opts): (C, R, Opts)) -> Self {
+        Arg {
+            arg: join_args(&[&center.into_arg(), &radius.into_arg()]),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
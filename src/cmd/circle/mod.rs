@@ -0,0 +1,56 @@
+mod arg;
+mod opt;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl r {
+    /// Construct a circular line or polygon
+    ///
+    /// Accepts a `(center, radius)` tuple, optionally followed by [Opts]. A
+    /// circle in RethinkDB is a polygon or line approximating a circle of a
+    /// given radius around a given center, consisting of a specified number
+    /// of vertices (default 32).
+    ///
+    /// ## Example
+    ///
+    /// Define a circle.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.circle((r.point((-122.423246, 37.770378359)), 50))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Define a circle with a radius of 5 kilometers, approximated by a line.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::circle::{Opts, Unit}};
+    /// #
+    /// let opts = Opts::builder().unit(Unit::Kilometer).fill(false).build();
+    /// r.circle(([-122.423246, 37.770378359], 5, opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.point]
+    /// - [r.polygon]
+    /// - [distance]
+    ///
+    /// [Opts]: cmd/circle/struct.Opts.html
+    /// [r.point]: struct.r.html#method.point
+    /// [r.polygon]: struct.r.html#method.polygon
+    /// [distance]: struct.Client.html#method.distance
+    pub fn circle<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 165, arg.into())
+    }
+}
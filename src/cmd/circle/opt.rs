@@ -0,0 +1,73 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    num_vertices: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    geo_system: Option<GeoSystem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<Unit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill: Option<bool>,
+}
+
+/// The reference ellipsoid to use for geographic coordinates
+#[derive(Debug, Clone, Copy, Serialize)]
+pub enum GeoSystem {
+    #[serde(rename = "WGS84")]
+    Wgs84,
+    #[serde(rename = "unit_sphere")]
+    UnitSphere,
+}
+
+/// A unit of distance
+#[derive(Debug, Clone, Copy, Serialize)]
+pub enum Unit {
+    #[serde(rename = "m")]
+    Meter,
+    #[serde(rename = "km")]
+    Kilometer,
+    #[serde(rename = "mi")]
+    InternationalMile,
+    #[serde(rename = "nm")]
+    NauticalMile,
+    #[serde(rename = "ft")]
+    InternationalFoot,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// The number of vertices in the polygon or line (default `32`)
+    pub fn num_vertices(&mut self, num_vertices: u32) -> &mut Self {
+        self.num_vertices = Some(num_vertices);
+        self
+    }
+
+    /// The reference ellipsoid to use (default `WGS84`)
+    pub fn geo_system(&mut self, geo_system: GeoSystem) -> &mut Self {
+        self.geo_system = Some(geo_system);
+        self
+    }
+
+    /// The unit of the radius (default `m`)
+    pub fn unit(&mut self, unit: Unit) -> &mut Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Return a polygon instead of a line (default `true`)
+    pub fn fill(&mut self, fill: bool) -> &mut Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
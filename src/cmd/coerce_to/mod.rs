@@ -0,0 +1,52 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Convert a value of one type into another
+    ///
+    /// - a sequence, selection or object can be coerced to an array
+    /// - a sequence, selection or an array of key/value pairs can be coerced to an
+    ///   object
+    /// - a string can be coerced to a number
+    /// - any datum (single value) can be coerced to a string
+    /// - a binary object can be coerced to a string and vice-versa
+    ///
+    /// ## Example
+    ///
+    /// Coerce a stream to an array to store its output in a field.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("posts").coerce_to("array")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Convert a number to a string.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(1).coerce_to("string")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [type_of]
+    /// - [r.object]
+    ///
+    /// [type_of]: struct.Client.html#method.type_of
+    /// [r.object]: struct.r.html#method.object
+    pub fn coerce_to<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 51, arg.into())
+    }
+}
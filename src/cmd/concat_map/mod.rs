@@ -0,0 +1,38 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Concatenate one or more elements into a single sequence using a mapping
+    /// function
+    ///
+    /// `concat_map` works in a similar fashion to [map], applying the given
+    /// function to each element in a sequence, but it will always return a single
+    /// sequence.
+    ///
+    /// ## Example
+    ///
+    /// Construct a sequence of all monsters defeated by Marvel heroes, where
+    /// the field `defeatedMonsters` is an array of one or more monster names.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("marvel").concat_map(|hero: Client| hero.get_field("defeatedMonsters"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [map]
+    ///
+    /// [map]: struct.Client.html#method.map
+    pub fn concat_map<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 40, arg.into())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::Client;
+
+impl Client {
+    /// Query (read and/or update) the configurations for individual tables or
+    /// databases
+    ///
+    /// The `config` command is a shorthand way to access the `table_config` or
+    /// `db_config` [System tables]. It will return the single row from the system
+    /// that corresponds to the database or table configuration, as if [get] had
+    /// been called on the system table with the UUID of the database or table in
+    /// question. A table's configuration can be deserialised into a [TableConfig]
+    /// and a database's into a [DbConfig].
+    ///
+    /// ## Example
+    ///
+    /// Get the configuration for the `users` table.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").config()
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Change the write acknowledgement requirement of the `users` table.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("users").config().update(json!({"write_acks": "single"}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [status]
+    /// - [reconfigure]
+    ///
+    /// [status]: struct.Client.html#method.status
+    /// [reconfigure]: struct.Client.html#method.reconfigure
+    /// [System tables]: https://rethinkdb.com/docs/system-tables/
+    /// [get]: struct.Client.html#method.get
+    /// [TableConfig]: https://docs.rs/reql-types/*/reql_types/struct.TableConfig.html
+    /// [DbConfig]: https://docs.rs/reql-types/*/reql_types/struct.DbConfig.html
+    pub fn config(&self) -> Client {
+        Client::new(&self.0, 174, ())
+    }
+}
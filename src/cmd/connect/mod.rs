@@ -1,7 +1,10 @@
 pub(crate) mod opt;
 
 use {
-    crate::{net::connection::Connection, r, Result},
+    crate::{
+        net::connection::{Connection, Stream},
+        r, Result,
+    },
     futures::prelude::*,
     romio::TcpStream,
     std::net::SocketAddr,
@@ -64,6 +67,21 @@ impl r {
     /// # ;
     /// ```
     ///
+    /// ## Example
+    ///
+    /// Open an encrypted connection to a server whose certificate was issued
+    /// by a private certificate authority.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::connect::{Opts, Tls}};
+    /// #
+    /// let ca = std::fs::read("tests/tls/cert.pem").unwrap();
+    /// let tls = Tls::builder().ca_certs(&ca).domain("localhost").build();
+    /// let opts = Opts::builder().port(28015).tls(tls).build();
+    /// r.connect(opts)
+    /// # ;
+    /// ```
+    ///
     /// ## Related commands
     ///
     /// - [use_db]
@@ -78,17 +96,29 @@ impl r {
             let opts = opts.into();
             let stream = {
                 let addr = SocketAddr::new(opts.host, opts.port);
-                await!(TcpStream::connect(&addr))?
+                let stream = await!(TcpStream::connect(&addr))?;
+                match opts.tls {
+                    Some(tls) => await!(Stream::tls(stream, tls, opts.host))?,
+                    None => Stream::Tcp(stream),
+                }
             };
-            await!(Connection::new(opts.db, stream).hand_shake(opts))
+            await!(Connection::new(opts.db, stream)?.hand_shake(opts))
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::{Opts, Tls};
     use crate::r;
     use futures::executor::block_on;
+    use native_tls::{Identity, TlsAcceptor};
+    use std::{
+        io::{Read, Write},
+        net::{Shutdown, TcpListener, TcpStream},
+        sync::{Arc, Mutex},
+        thread::{self, JoinHandle},
+    };
 
     #[test]
     fn driver_can_connect() -> crate::Result<()> {
@@ -99,4 +129,96 @@ mod tests {
             },
         )
     }
+
+    #[test]
+    fn driver_can_connect_over_tls() -> crate::Result<()> {
+        let (port, proxy) = tls_proxy();
+        let ca = include_bytes!("../../../tests/tls/cert.pem");
+        let tls = Tls::builder().ca_certs(ca).domain("localhost").build();
+        let opts = Opts::builder().port(port).tls(tls).build();
+        block_on(
+            async {
+                let conn = await!(r.connect(opts))?;
+                let resp = await!(r.expr("encrypted").run::<_, String>(&conn))?;
+                assert_eq!(resp.first().unwrap(), "encrypted");
+                Ok(())
+            },
+        )?;
+        // the proxy exits once the connection is dropped
+        proxy.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn driver_can_connect_over_tls_with_a_client_certificate() -> crate::Result<()> {
+        let (port, proxy) = tls_proxy();
+        let ca = include_bytes!("../../../tests/tls/cert.pem");
+        let cert = include_bytes!("../../../tests/tls/client.pem");
+        let key = include_bytes!("../../../tests/tls/client.key");
+        let tls = Tls::builder()
+            .ca_certs(ca)
+            .cert_and_key(cert, key)
+            .domain("localhost")
+            .build();
+        let opts = Opts::builder().port(port).tls(tls).build();
+        block_on(
+            async {
+                let conn = await!(r.connect(opts))?;
+                let resp = await!(r.expr("authenticated").run::<_, String>(&conn))?;
+                assert_eq!(resp.first().unwrap(), "authenticated");
+                Ok(())
+            },
+        )?;
+        proxy.join().unwrap();
+        Ok(())
+    }
+
+    // Terminates TLS in front of the local server, using the self-signed
+    // certificate for `localhost` in `tests/tls`. It serves a single
+    // connection.
+    fn tls_proxy() -> (u16, JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let identity = include_bytes!("../../../tests/tls/server.p12");
+        let acceptor = TlsAcceptor::new(Identity::from_pkcs12(identity, "reql").unwrap()).unwrap();
+        let proxy = thread::spawn(move || {
+            let (client, _) = listener.accept().unwrap();
+            let socket = client.try_clone().unwrap();
+            let client = Arc::new(Mutex::new(acceptor.accept(client).unwrap()));
+            let mut server = TcpStream::connect("127.0.0.1:28015").unwrap();
+            let responses = {
+                let client = client.clone();
+                let mut server = server.try_clone().unwrap();
+                thread::spawn(move || {
+                    let mut buf = [0; 4096];
+                    loop {
+                        let len = match server.read(&mut buf) {
+                            Ok(0) | Err(..) => break,
+                            Ok(len) => len,
+                        };
+                        if client.lock().unwrap().write_all(&buf[..len]).is_err() {
+                            break;
+                        }
+                    }
+                })
+            };
+            // The TLS stream is only locked once a record has started to
+            // arrive, so the responses can be written in the meantime. A read
+            // consumes a whole record, leaving nothing buffered behind.
+            let mut buf = [0; 16 * 1024];
+            while let Ok(1) = socket.peek(&mut [0]) {
+                let len = match client.lock().unwrap().read(&mut buf) {
+                    Ok(0) | Err(..) => break,
+                    Ok(len) => len,
+                };
+                if server.write_all(&buf[..len]).is_err() {
+                    break;
+                }
+            }
+            // stops the other direction too
+            let _ = server.shutdown(Shutdown::Both);
+            responses.join().unwrap();
+        });
+        (port, proxy)
+    }
 }
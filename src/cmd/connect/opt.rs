@@ -8,6 +8,25 @@ pub struct Opts<'a> {
     pub(crate) user: &'a str,
     pub(crate) password: &'a str,
     pub(crate) timeout: u8,
+    pub(crate) tls: Option<Tls<'a>>,
+}
+
+/// The settings for encrypting a connection with TLS
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Tls<'a> {
+    pub(crate) ca_certs: Option<&'a [u8]>,
+    pub(crate) identity: Option<Identity<'a>>,
+    pub(crate) domain: Option<&'a str>,
+    pub(crate) insecure: bool,
+}
+
+/// The client certificate and private key to authenticate with
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Identity<'a> {
+    /// PEM encoded certificate chain and PKCS #8 key
+    Pem(&'a [u8], &'a [u8]),
+    /// DER encoded PKCS #12 archive and its password
+    Pkcs12(&'a [u8], &'a str),
 }
 
 impl<'a> Opts<'a> {
@@ -45,6 +64,57 @@ impl<'a> Opts<'a> {
         self.password = password;
         self
     }
+
+    /// Encrypt the connection with TLS (default: unencrypted)
+    pub fn tls(&mut self, tls: Tls<'a>) -> &mut Self {
+        self.tls = Some(tls);
+        self
+    }
+}
+
+impl<'a> Tls<'a> {
+    make_builder!();
+
+    /// Trust the certificate authorities in this PEM encoded bundle, in
+    /// addition to the ones trusted by the system
+    pub fn ca_certs(&mut self, pem: &'a [u8]) -> &mut Self {
+        self.ca_certs = Some(pem);
+        self
+    }
+
+    /// Authenticate with a client certificate and its private key, both PEM
+    /// encoded
+    ///
+    /// The certificate may be followed by its intermediate certificates. The
+    /// key must be in PKCS #8 format, starting with `BEGIN PRIVATE KEY`.
+    pub fn cert_and_key(&mut self, cert: &'a [u8], key: &'a [u8]) -> &mut Self {
+        self.identity = Some(Identity::Pem(cert, key));
+        self
+    }
+
+    /// Authenticate with a client certificate and its private key, given as
+    /// a DER encoded PKCS #12 archive and the password to decrypt it
+    pub fn identity(&mut self, pkcs12: &'a [u8], password: &'a str) -> &mut Self {
+        self.identity = Some(Identity::Pkcs12(pkcs12, password));
+        self
+    }
+
+    /// The name the server certificate is verified against, also sent to the
+    /// server for SNI (default: the host address)
+    pub fn domain(&mut self, domain: &'a str) -> &mut Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Accept any server certificate, even invalid or expired ones or ones
+    /// issued for another name (default `false`)
+    ///
+    /// This makes the connection vulnerable to man-in-the-middle attacks so
+    /// only use it during development.
+    pub fn insecure(&mut self, insecure: bool) -> &mut Self {
+        self.insecure = insecure;
+        self
+    }
 }
 
 impl<'a> Default for Opts<'a> {
@@ -56,6 +126,7 @@ impl<'a> Default for Opts<'a> {
             user: "admin",
             password: "",
             timeout: 20,
+            tls: None,
         }
     }
 }
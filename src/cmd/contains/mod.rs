@@ -0,0 +1,43 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Test if a sequence contains the given value
+    ///
+    /// When passed a predicate function, test if it returns `true` for any element
+    /// of the sequence. To test for more than one value at once, pass them using
+    /// [r.args]; all of them must be contained in the sequence.
+    ///
+    /// ## Example
+    ///
+    /// Has Iron Man ever fought Superman?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["joker", "superman", "thanos"]).contains("superman")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Are both fire and water among the elements?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["fire", "earth", "water"]).contains(r.args(["fire", "water"]))
+    /// # ;
+    /// ```
+    ///
+    /// [r.args]: struct.r.html#method.args
+    pub fn contains<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 93, arg.into())
+    }
+}
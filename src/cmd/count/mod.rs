@@ -0,0 +1,53 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Count the number of elements in a sequence or key/value pairs in an object,
+    /// or return the length of a string or binary object
+    ///
+    /// When passed a value, count the elements equal to it. Values other than
+    /// strings are passed as queries built with [r.expr]. When passed a
+    /// predicate function, count the elements for which it returns `true`.
+    ///
+    /// ## Example
+    ///
+    /// Count the number of users.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").count(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Count the number of 18 year olds.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr([18, 21, 18, 30]).count(r.expr(18))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [sum]
+    /// - [avg]
+    /// - [group]
+    ///
+    /// [r.expr]: struct.r.html#method.expr
+    /// [sum]: struct.Client.html#method.sum
+    /// [avg]: struct.Client.html#method.avg
+    /// [group]: struct.Client.html#method.group
+    pub fn count<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 43, arg.into())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::Client;
+
+impl Client {
+    /// Return a new time object only based on the day, month and year (ie. the
+    /// same day at 00:00)
+    ///
+    /// ## Example
+    ///
+    /// Retrieve all the users whose birthday is today.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("users").filter(|user: Client| user.get_field("birthdate").date().eq(r.now().date()))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [time_of_day]
+    /// - [r.now]
+    ///
+    /// [time_of_day]: struct.Client.html#method.time_of_day
+    /// [r.now]: struct.r.html#method.now
+    pub fn date(&self) -> Client {
+        Client::new(&self.0, 106, ())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Return the day of a time object as a number between 1 and 31
+    ///
+    /// ## Example
+    ///
+    /// Return the users born on the 24th of any month.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("users").filter(|user: Client| user.get_field("birthdate").day().eq(24))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [year]
+    /// - [month]
+    ///
+    /// [year]: struct.Client.html#method.year
+    /// [month]: struct.Client.html#method.month
+    pub fn day(&self) -> Client {
+        Client::new(&self.0, 130, ())
+    }
+}
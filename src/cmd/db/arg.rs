@@ -1,5 +1,8 @@
 use {
-    crate::{cmd::Param, ser::to_vec},
+    crate::{
+        cmd::{Args, Param},
+        ser::to_vec,
+    },
     bytes::Bytes,
 };
 
@@ -27,12 +30,21 @@ impl From<String> for Arg {
     }
 }
 
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
 impl Param for Arg {
-    fn arg(&self) -> &Bytes {
+    fn arg(&self) -> &[u8] {
         &self.arg
     }
 
-    fn opts(&self) -> &Vec<u8> {
+    fn opts(&self) -> &[u8] {
         &self.opts
     }
 }
@@ -0,0 +1,42 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Create a database
+    ///
+    /// A RethinkDB database is a collection of tables, similar to relational
+    /// databases. The result of the query can be deserialised into a
+    /// [DbCreateStatus]. If a database with the same name already exists, the
+    /// command throws a runtime error.
+    ///
+    /// ## Example
+    ///
+    /// Create a database named `superheroes`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.db_create("superheroes")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [db_drop]
+    /// - [db_list]
+    /// - [table_create]
+    ///
+    /// [DbCreateStatus]: https://docs.rs/reql-types/*/reql_types/struct.DbCreateStatus.html
+    /// [db_drop]: struct.r.html#method.db_drop
+    /// [db_list]: struct.r.html#method.db_list
+    /// [table_create]: struct.r.html#method.table_create
+    pub fn db_create<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 57, arg.into())
+    }
+}
@@ -0,0 +1,41 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Drop a database
+    ///
+    /// The database, all its tables, and corresponding data will be deleted.
+    /// The result of the query can be deserialised into a [DbDropStatus]. If
+    /// the given database does not exist, the command throws a runtime error.
+    ///
+    /// ## Example
+    ///
+    /// Drop a database named `superheroes`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.db_drop("superheroes")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [db_create]
+    /// - [db_list]
+    /// - [table_create]
+    ///
+    /// [DbDropStatus]: https://docs.rs/reql-types/*/reql_types/struct.DbDropStatus.html
+    /// [db_create]: struct.r.html#method.db_create
+    /// [db_list]: struct.r.html#method.db_list
+    /// [table_create]: struct.r.html#method.table_create
+    pub fn db_drop<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 58, arg.into())
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{r, Client};
+
+impl r {
+    /// List all database names in the system
+    ///
+    /// The result is a list of strings, so it can be deserialised into a
+    /// `Vec<String>`.
+    ///
+    /// ## Example
+    ///
+    /// List all databases.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.db_list()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [db_create]
+    /// - [db_drop]
+    /// - [table_create]
+    ///
+    /// [db_create]: struct.r.html#method.db_create
+    /// [db_drop]: struct.r.html#method.db_drop
+    /// [table_create]: struct.r.html#method.table_create
+    pub fn db_list(&self) -> Client {
+        Client::new(&[], 59, ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+    use futures::executor::block_on;
+
+    #[test]
+    fn rethinkdb_database_is_listed() -> crate::Result<()> {
+        block_on(
+            async {
+                let conn = await!(r.connect(()))?;
+                let resp = await!(r.db_list().run::<_, Vec<String>>(&conn))?;
+                let dbs = resp.first().unwrap();
+                assert!(dbs.iter().any(|db| db == "rethinkdb"));
+                Ok(())
+            },
+        )
+    }
+}
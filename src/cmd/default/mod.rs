@@ -0,0 +1,41 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Provide a default value in case of non-existence errors
+    ///
+    /// The `default` command evaluates its first argument (the value it's chained
+    /// to). If that argument returns `null` or a non-existence error is thrown in
+    /// evaluation, then `default` returns its second argument. The second argument
+    /// is usually a default value, but it can be a function that returns a value.
+    ///
+    /// ## Example
+    ///
+    /// Suppose we want to retrieve the titles and authors of the table `posts`.
+    /// In the case where the author field is missing or `null`, we want to
+    /// retrieve the string `Anonymous`.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("posts").map(|post: Client| {
+    ///     post.get_field("author").default("Anonymous")
+    /// })
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.error]
+    ///
+    /// [r.error]: struct.r.html#method.error
+    pub fn default<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 92, arg.into())
+    }
+}
@@ -0,0 +1,59 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Delete one or more documents from a table
+    ///
+    /// Pass `()` to use the default options. The result of the query can be
+    /// deserialised into a [WriteStatus].
+    ///
+    /// ## Example
+    ///
+    /// Delete all comments.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("comments").delete(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Delete all comments with soft durability and return the deleted
+    /// documents.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use reql::cmd::delete::{Durability, Opts};
+    /// #
+    /// let opts = Opts::builder()
+    ///     .durability(Durability::Soft)
+    ///     .return_changes(true)
+    ///     .build();
+    /// r.table("comments").delete(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [insert]
+    /// - [update]
+    /// - [replace]
+    ///
+    /// [WriteStatus]: https://docs.rs/reql-types/*/reql_types/struct.WriteStatus.html
+    /// [insert]: struct.Client.html#method.insert
+    /// [update]: struct.Client.html#method.update
+    /// [replace]: struct.Client.html#method.replace
+    pub fn delete<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 54, arg.into())
+    }
+}
@@ -0,0 +1,52 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Remove one or more elements from an array at a given index
+    ///
+    /// Negative indexes count from the end of the array. To remove a range of
+    /// elements, pass the start index and the (exclusive) end index using
+    /// [r.args].
+    ///
+    /// ## Example
+    ///
+    /// Delete the second element of an array.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["a", "b", "c", "d", "e", "f"]).delete_at(1)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Delete a range of elements of an array.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["a", "b", "c", "d", "e", "f"]).delete_at(r.args([1, 3]))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [insert_at]
+    /// - [splice_at]
+    /// - [change_at]
+    ///
+    /// [r.args]: struct.r.html#method.args
+    /// [insert_at]: struct.Client.html#method.insert_at
+    /// [splice_at]: struct.Client.html#method.splice_at
+    /// [change_at]: struct.Client.html#method.change_at
+    pub fn delete_at<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 83, arg.into())
+    }
+}
@@ -0,0 +1,34 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Specify descending order for [order_by]
+    ///
+    /// ## Example
+    ///
+    /// Sort heroes by their names in descending order.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").order_by(r.desc("name"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [asc]
+    /// - [order_by]
+    ///
+    /// [asc]: struct.r.html#method.asc
+    /// [order_by]: struct.Client.html#method.order_by
+    pub fn desc<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 74, arg.into())
+    }
+}
@@ -0,0 +1,34 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Remove the elements of one array from another array
+    ///
+    /// ## Example
+    ///
+    /// Retrieve Iron Man's equipment list without boots.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").get_field("equipment").difference(["Boots"])
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [set_difference]
+    /// - [without]
+    ///
+    /// [set_difference]: struct.Client.html#method.set_difference
+    /// [without]: struct.Client.html#method.without
+    pub fn difference<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 95, arg.into())
+    }
+}
@@ -0,0 +1,43 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Compute the distance between a point and another geometry object
+    ///
+    /// At least one of the geometry objects must be a point. The unit and
+    /// reference ellipsoid can be changed through [Opts].
+    ///
+    /// ## Example
+    ///
+    /// Compute the distance between two points on the Earth in kilometers.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::distance::{Opts, Unit}};
+    /// #
+    /// let point1 = r.point((-122.423246, 37.779388));
+    /// let point2 = r.point((-117.220406, 32.719464));
+    /// let opts = Opts::builder().unit(Unit::Kilometer).build();
+    /// point1.distance((point2, opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.point]
+    /// - [get_nearest]
+    ///
+    /// [Opts]: cmd/distance/struct.Opts.html
+    /// [r.point]: struct.r.html#method.point
+    /// [get_nearest]: struct.Client.html#method.get_nearest
+    pub fn distance<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 162, arg.into())
+    }
+}
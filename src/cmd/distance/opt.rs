@@ -0,0 +1,35 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+pub use crate::cmd::circle::{GeoSystem, Unit};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    geo_system: Option<GeoSystem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<Unit>,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// The reference ellipsoid to use (default `WGS84`)
+    pub fn geo_system(&mut self, geo_system: GeoSystem) -> &mut Self {
+        self.geo_system = Some(geo_system);
+        self
+    }
+
+    /// The unit to return the distance in (default `m`)
+    pub fn unit(&mut self, unit: Unit) -> &mut Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
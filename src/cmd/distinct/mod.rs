@@ -0,0 +1,55 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Remove duplicate elements from a sequence
+    ///
+    /// On a table, the unique values of a secondary index can be returned
+    /// through [Opts].
+    ///
+    /// ## Example
+    ///
+    /// Remove the duplicate villains from a list.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["doom", "loki", "doom"]).distinct(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Topics in a table of messages have a secondary index on them, and you
+    /// want to know how many unique topics there are.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::distinct::Opts};
+    /// #
+    /// let opts = Opts::builder().index("topics").build();
+    /// r.table("messages").distinct(opts).count(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [map]
+    /// - [concat_map]
+    /// - [group]
+    ///
+    /// [Opts]: cmd/distinct/struct.Opts.html
+    /// [map]: struct.Client.html#method.map
+    /// [concat_map]: struct.Client.html#method.concat_map
+    /// [group]: struct.Client.html#method.group
+    pub fn distinct<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 42, arg.into())
+    }
+}
@@ -0,0 +1 @@
+pub use crate::cmd::min::Opts;
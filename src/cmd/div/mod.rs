@@ -0,0 +1,65 @@
+mod arg;
+
+use {
+    crate::{cmd::IntoArg, Client},
+    std::ops::Div,
+};
+
+pub use arg::Arg;
+
+impl Client {
+    /// Divide two numbers
+    ///
+    /// The `/` operator can be used as well.
+    ///
+    /// ## Example
+    ///
+    /// It's as easy as 2 / 2 = 1.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(2).div(2)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Use the operator instead.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(2) / 2
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [add]
+    /// - [sub]
+    /// - [mul]
+    /// - [mod_]
+    ///
+    /// [add]: struct.Client.html#method.add
+    /// [sub]: struct.Client.html#method.sub
+    /// [mul]: struct.Client.html#method.mul
+    /// [mod_]: struct.Client.html#method.mod_
+    pub fn div<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 27, arg.into())
+    }
+}
+
+impl<T> Div<T> for Client
+where
+    T: IntoArg,
+{
+    type Output = Client;
+
+    fn div(self, rhs: T) -> Client {
+        Client::div(&self, rhs)
+    }
+}
@@ -0,0 +1,80 @@
+use {
+    crate::cmd::{join_args, Args, Datum, IntoArg, Param},
+    bytes::Bytes,
+    std::marker::PhantomData,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg<K> {
+    arg: Bytes,
+    opts: Vec<u8>,
+    kind: PhantomData<K>,
+}
+
+// `FUNCALL` takes the function first even though it's passed last
+impl<K> Arg<K> {
+    fn new(func: &[u8], args: &[&[u8]]) -> Self {
+        let mut all = Vec::with_capacity(args.len() + 1);
+        all.push(func);
+        all.extend_from_slice(args);
+        Arg {
+            arg: join_args(&all),
+            opts: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<A, F, K> From<(A, F)> for Arg<K>
+where
+    A: IntoArg,
+    F: IntoArg<K>,
+{
+    fn from((a, func): (A, F)) -> Self {
+        Arg::new(&func.into_arg(), &[&a.into_arg()])
+    }
+}
+
+impl<A, B, F, K> From<(A, B, F)> for Arg<K>
+where
+    A: IntoArg,
+    B: IntoArg,
+    F: IntoArg<K>,
+{
+    fn from((a, b, func): (A, B, F)) -> Self {
+        Arg::new(&func.into_arg(), &[&a.into_arg(), &b.into_arg()])
+    }
+}
+
+impl<A, B, C, F, K> From<(A, B, C, F)> for Arg<K>
+where
+    A: IntoArg,
+    B: IntoArg,
+    C: IntoArg,
+    F: IntoArg<K>,
+{
+    fn from((a, b, c, func): (A, B, C, F)) -> Self {
+        let args = [&a.into_arg()[..], &b.into_arg(), &c.into_arg()];
+        Arg::new(&func.into_arg(), &args)
+    }
+}
+
+impl From<Args> for Arg<Datum> {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> Param for Arg<K> {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,69 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Call a function with one or more values as its arguments
+    ///
+    /// Accepts a tuple of up to three values followed by the function, which
+    /// is called with those values. Results in a `FUNCALL` term, which lets a
+    /// value computed once be reused several times inside a query.
+    ///
+    /// ## Example
+    ///
+    /// Compute a golfer's net score for a game.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.do_((r.table("players").get(86), |player: Client| {
+    ///     player.get_field("gross_score") - player.get_field("course_handicap")
+    /// }))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Return the sum of two numbers.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.do_((1, 2, |a: Client, b: Client| a + b))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [map]
+    /// - [r.branch]
+    ///
+    /// [map]: struct.Client.html#method.map
+    /// [r.branch]: struct.r.html#method.branch
+    pub fn do_<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&[], 64, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{r, Client},
+        serde_json::Value,
+    };
+
+    #[test]
+    fn function_is_serialised_before_its_arguments() {
+        let query = r.do_((1, 2, |a: Client, b: Client| a + b));
+        let term: Value = serde_json::from_slice(&query.0).unwrap();
+        assert_eq!(term[0], 64);
+        assert_eq!(term[1][0][0], 69);
+        assert_eq!(term[1][1], 1);
+        assert_eq!(term[1][2], 2);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Lowercase a string
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr("Sentence about LaTeX.").downcase()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [upcase]
+    /// - [match_]
+    /// - [split]
+    ///
+    /// [upcase]: struct.Client.html#method.upcase
+    /// [match_]: struct.Client.html#method.match_
+    /// [split]: struct.Client.html#method.split
+    pub fn downcase(&self) -> Client {
+        Client::new(&self.0, 142, ())
+    }
+}
@@ -0,0 +1,56 @@
+use {
+    super::Opts,
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<S, E> From<(S, E)> for Arg
+where
+    S: IntoArg,
+    E: IntoArg,
+{
+    fn from((start, end): (S, E)) -> Self {
+        Arg {
+            arg: join_args(&[&start.into_arg(), &end.into_arg()]),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<S, E> From<(S, E, Opts)> for Arg
+where
+    S: IntoArg,
+    E: IntoArg,
+{
+    fn from((start, end, opts): (S, E, Opts)) -> Self {
+        Arg {
+            arg: join_args(&[&start.into_arg(), &end.into_arg()]),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,65 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Return whether a time is between two other times
+    ///
+    /// Accepts a `(start, end)` tuple, optionally followed by [Opts]. By
+    /// default the start time is included in the range and the end time is
+    /// not.
+    ///
+    /// ## Example
+    ///
+    /// Retrieve all the posts that were posted between December 1st, 2013
+    /// (inclusive) and December 10th, 2013 (exclusive).
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("posts").filter(|post: Client| {
+    ///     post.get_field("date")
+    ///         .during((r.time((2013, 12, 1, "Z")), r.time((2013, 12, 10, "Z"))))
+    /// })
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Retrieve all the posts that were posted between December 1st, 2013
+    /// (exclusive) and December 10th, 2013 (inclusive).
+    ///
+    /// ```rust
+    /// # use reql::{r, Client, cmd::during::{Bound, Opts}};
+    /// #
+    /// let opts = Opts::builder()
+    ///     .left_bound(Bound::Open)
+    ///     .right_bound(Bound::Closed)
+    ///     .build();
+    /// r.table("posts").filter(|post: Client| {
+    ///     let start = r.time((2013, 12, 1, "Z"));
+    ///     let end = r.time((2013, 12, 10, "Z"));
+    ///     post.get_field("date").during((start, end, opts))
+    /// })
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [date]
+    /// - [time_of_day]
+    ///
+    /// [Opts]: cmd/during/struct.Opts.html
+    /// [date]: struct.Client.html#method.date
+    /// [time_of_day]: struct.Client.html#method.time_of_day
+    pub fn during<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 105, arg.into())
+    }
+}
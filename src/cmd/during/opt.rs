@@ -0,0 +1,35 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+pub use crate::cmd::between::Bound;
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    left_bound: Option<Bound>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    right_bound: Option<Bound>,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// Whether the start time is included in the range (default `closed`)
+    pub fn left_bound(&mut self, bound: Bound) -> &mut Self {
+        self.left_bound = Some(bound);
+        self
+    }
+
+    /// Whether the end time is included in the range (default `open`)
+    pub fn right_bound(&mut self, bound: Bound) -> &mut Self {
+        self.right_bound = Some(bound);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
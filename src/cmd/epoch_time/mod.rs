@@ -0,0 +1,39 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Create a time object based on seconds since epoch
+    ///
+    /// The argument is a number of seconds, with millisecond precision. The
+    /// timezone is UTC.
+    ///
+    /// ## Example
+    ///
+    /// Create a time object for November 3rd, 1986.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.epoch_time(531360000)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.now]
+    /// - [r.time]
+    /// - [r.iso8601]
+    ///
+    /// [r.now]: struct.r.html#method.now
+    /// [r.time]: struct.r.html#method.time
+    /// [r.iso8601]: struct.r.html#method.iso8601
+    pub fn epoch_time<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 101, arg.into())
+    }
+}
@@ -0,0 +1,35 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Test if two or more values are equal
+    ///
+    /// To compare more than two values, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// See if a user's `role` field is set to `administrator`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").get(1).get_field("role").eq("administrator")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [ne]
+    ///
+    /// [ne]: struct.Client.html#method.ne
+    /// [r.args]: struct.r.html#method.args
+    pub fn eq<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 17, arg.into())
+    }
+}
@@ -0,0 +1,61 @@
+use {
+    super::Opts,
+    crate::cmd::{join_args, Args, Datum, IntoArg, Param, WithOpts},
+    bytes::Bytes,
+    std::marker::PhantomData,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg<K> {
+    arg: Bytes,
+    opts: Vec<u8>,
+    kind: PhantomData<K>,
+}
+
+impl<F, T, K> From<(F, T)> for Arg<K>
+where
+    F: IntoArg<K>,
+    T: IntoArg,
+{
+    fn from((field, table): (F, T)) -> Self {
+        Arg {
+            arg: join_args(&[&field.into_arg(), &table.into_arg()]),
+            opts: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<'a, F, T, K> From<(F, T, Opts<'a>)> for Arg<WithOpts<K>>
+where
+    F: IntoArg<K>,
+    T: IntoArg,
+{
+    fn from((field, table, opts): (F, T, Opts<'a>)) -> Self {
+        Arg {
+            arg: join_args(&[&field.into_arg(), &table.into_arg()]),
+            opts: opts.to_vec(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl From<Args> for Arg<Datum> {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> Param for Arg<K> {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,59 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Join tables using a field of the left sequence and the primary key
+    /// or an index of the right table
+    ///
+    /// Accepts a `(left_field, right_table)` tuple, optionally followed by
+    /// [Opts]. Each result is an object with a `left` and a `right` field,
+    /// which can be deserialised into a [Joined] value. Unlike [inner_join],
+    /// `eq_join` uses an index, so it is much faster.
+    ///
+    /// ## Example
+    ///
+    /// Match players with the games they've played against one another.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("players").eq_join(("game_id", r.table("games")))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Use a secondary index on the right table rather than the primary key,
+    /// keeping the order of the left table.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::eq_join::Opts};
+    /// #
+    /// let opts = Opts::builder().index("city_id").ordered(true).build();
+    /// r.table("players").eq_join(("id", r.table("arenas"), opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [inner_join]
+    /// - [outer_join]
+    /// - [zip]
+    ///
+    /// [Opts]: cmd/eq_join/struct.Opts.html
+    /// [Joined]: https://docs.rs/reql-types/*/reql_types/struct.Joined.html
+    /// [inner_join]: struct.Client.html#method.inner_join
+    /// [outer_join]: struct.Client.html#method.outer_join
+    /// [zip]: struct.Client.html#method.zip
+    pub fn eq_join<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 50, arg.into())
+    }
+}
@@ -0,0 +1,33 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ordered: Option<bool>,
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// The index of the right table to join on (default: the primary key)
+    pub fn index(&mut self, index: &'a str) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Order the results by the fields of the left sequence (default `false`)
+    pub fn ordered(&mut self, ordered: bool) -> &mut Self {
+        self.ordered = Some(ordered);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
@@ -0,0 +1,35 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Throw a runtime error
+    ///
+    /// If called with `()` inside the second argument to [default],
+    /// re-throw the current error.
+    ///
+    /// ## Example
+    ///
+    /// Fail with a custom message.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.error("Impossible code path")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [default]
+    ///
+    /// [default]: struct.Client.html#method.default
+    pub fn error<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 12, arg.into())
+    }
+}
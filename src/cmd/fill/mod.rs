@@ -0,0 +1,30 @@
+use crate::Client;
+
+impl Client {
+    /// Convert a Line object into a Polygon object
+    ///
+    /// If the last point does not specify the same coordinates as the first point,
+    /// `polygon` will close the polygon by connecting them.
+    ///
+    /// ## Example
+    ///
+    /// Create a line object and then convert it to a polygon.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.line(vec![[-122.423246, 37.779388], [-122.423246, 37.329898], [-121.886420, 37.329898]]).fill()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.line]
+    /// - [r.polygon]
+    ///
+    /// [r.line]: struct.r.html#method.line
+    /// [r.polygon]: struct.r.html#method.polygon
+    pub fn fill(&self) -> Client {
+        Client::new(&self.0, 167, ())
+    }
+}
@@ -0,0 +1,72 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Return all the elements in a sequence for which the given predicate
+    /// is true
+    ///
+    /// The predicate can be an object, in which case the documents are
+    /// matched against its fields, or a term that evaluates to a boolean.
+    ///
+    /// By default, documents that are missing a field used in the predicate
+    /// are skipped. Set the `default` option to `true` to include them.
+    ///
+    /// ## Example
+    ///
+    /// Get all users who are 30 years old.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("users").filter(json!({"age": 30}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Get all users who are 30 years old, including those who don't have
+    /// an `age` field.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::filter::Opts};
+    /// # use serde_json::json;
+    /// #
+    /// let opts = Opts::builder().default(true).build();
+    /// r.table("users").filter((json!({"age": 30}), opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Get all users who are more than 18 years old and active.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("users")
+    ///     .filter(|user: Client| user.get_field("age").gt(18) & user.get_field("active"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get]
+    /// - [get_all]
+    /// - [between]
+    ///
+    /// [get]: struct.Client.html#method.get
+    /// [get_all]: struct.Client.html#method.get_all
+    /// [between]: struct.Client.html#method.between
+    pub fn filter<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 39, arg.into())
+    }
+}
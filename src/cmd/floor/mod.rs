@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Round a number down to the nearest integer
+    ///
+    /// ## Example
+    ///
+    /// Return the floor of 12.345.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(12.345).floor()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [ceil]
+    /// - [round]
+    ///
+    /// [ceil]: struct.Client.html#method.ceil
+    /// [round]: struct.Client.html#method.round
+    pub fn floor(&self) -> Client {
+        Client::new(&self.0, 183, ())
+    }
+}
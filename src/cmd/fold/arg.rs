@@ -0,0 +1,61 @@
+use {
+    super::Opts,
+    crate::cmd::{join_args, Args, Datum, IntoArg, Param, WithOpts},
+    bytes::Bytes,
+    std::marker::PhantomData,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg<K> {
+    arg: Bytes,
+    opts: Vec<u8>,
+    kind: PhantomData<K>,
+}
+
+impl<B, F, K> From<(B, F)> for Arg<K>
+where
+    B: IntoArg,
+    F: IntoArg<K>,
+{
+    fn from((base, func): (B, F)) -> Self {
+        Arg {
+            arg: join_args(&[&base.into_arg(), &func.into_arg()]),
+            opts: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<B, F, K> From<(B, F, Opts)> for Arg<WithOpts<K>>
+where
+    B: IntoArg,
+    F: IntoArg<K>,
+{
+    fn from((base, func, opts): (B, F, Opts)) -> Self {
+        Arg {
+            arg: join_args(&[&base.into_arg(), &func.into_arg()]),
+            opts: opts.to_vec(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl From<Args> for Arg<Datum> {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> Param for Arg<K> {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
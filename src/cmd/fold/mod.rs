@@ -0,0 +1,49 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Apply a function to a sequence in order, maintaining state via an
+    /// accumulator
+    ///
+    /// Accepts a `(base, function)` tuple, optionally followed by [Opts]. The
+    /// function is called with the accumulator and the next element of the
+    /// sequence, and returns the new accumulator, starting from `base`.
+    ///
+    /// Unlike [reduce], `fold` is guaranteed to process the sequence in order
+    /// but it can't be parallelised. The `emit` and `final_emit` options
+    /// turn it into a stream of values instead, which makes it possible to
+    /// express things like running totals.
+    ///
+    /// ## Example
+    ///
+    /// Concatenate words from a list.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("words")
+    ///     .order_by("id")
+    ///     .fold(("", |acc: Client, word: Client| acc + word))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [reduce]
+    /// - [concat_map]
+    ///
+    /// [Opts]: cmd/fold/struct.Opts.html
+    /// [reduce]: struct.Client.html#method.reduce
+    /// [concat_map]: struct.Client.html#method.concat_map
+    pub fn fold<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 187, arg.into())
+    }
+}
@@ -0,0 +1,60 @@
+use {crate::cmd::IntoArg, bytes::Bytes};
+
+// The options are functions, which serde can't serialise, so this doesn't
+// derive `Serialize` or use `make_builder` like the other options
+#[derive(Debug, Clone, Default)]
+pub struct Opts {
+    emit: Option<Bytes>,
+    final_emit: Option<Bytes>,
+}
+
+impl Opts {
+    /// Start building the options
+    pub fn builder() -> Self {
+        Default::default()
+    }
+
+    /// Finalise the options
+    pub fn build(&self) -> Self {
+        self.clone()
+    }
+
+    /// A function called with the accumulator, the element and the new
+    /// accumulator, returning an array of values to emit
+    ///
+    /// With `emit`, `fold` returns a stream of the emitted values instead of
+    /// the final accumulator.
+    pub fn emit<T, K>(&mut self, emit: T) -> &mut Self
+    where
+        T: IntoArg<K>,
+    {
+        self.emit = Some(emit.into_arg());
+        self
+    }
+
+    /// A function called with the final accumulator, returning an array of
+    /// values to emit at the end of the stream
+    pub fn final_emit<T, K>(&mut self, final_emit: T) -> &mut Self
+    where
+        T: IntoArg<K>,
+    {
+        self.final_emit = Some(final_emit.into_arg());
+        self
+    }
+
+    pub(crate) fn to_vec(&self) -> Vec<u8> {
+        let opts = [("emit", &self.emit), ("final_emit", &self.final_emit)];
+        let mut buf = Vec::new();
+        for (name, func) in opts.iter() {
+            if let Some(func) = func {
+                buf.push(if buf.is_empty() { b'{' } else { b',' });
+                buf.extend_from_slice(format!("\"{}\":", name).as_bytes());
+                buf.extend_from_slice(func);
+            }
+        }
+        if !buf.is_empty() {
+            buf.push(b'}');
+        }
+        buf
+    }
+}
@@ -0,0 +1,35 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Loop over a sequence, evaluating the given write query for each element
+    ///
+    /// ## Example
+    ///
+    /// Now that our heroes have defeated their villains, we can safely remove
+    /// them from the villain table.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("marvel").for_each(|hero: Client| {
+    ///     r.table("villains").get(hero.get_field("villain_defeated")).delete(())
+    /// })
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [map]
+    ///
+    /// [map]: struct.Client.html#method.map
+    pub fn for_each<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 68, arg.into())
+    }
+}
@@ -0,0 +1,41 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compare values, testing if the left-hand value is greater than or equal
+    /// to the right-hand
+    ///
+    /// To compare more than two values, pass them using [r.args]; they are tested
+    /// pairwise from left to right.
+    ///
+    /// ## Example
+    ///
+    /// Test if a player has scored 10 points or more.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("players").get(1).get_field("score").ge(10)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [gt]
+    /// - [lt]
+    /// - [le]
+    ///
+    /// [gt]: struct.Client.html#method.gt
+    /// [lt]: struct.Client.html#method.lt
+    /// [le]: struct.Client.html#method.le
+    /// [r.args]: struct.r.html#method.args
+    pub fn ge<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 22, arg.into())
+    }
+}
@@ -0,0 +1,40 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Convert a [GeoJSON] object to a ReQL geometry object
+    ///
+    /// RethinkDB only allows conversion of GeoJSON objects which have ReQL
+    /// equivalents: Point, LineString, and Polygon. MultiPoint, MultiLineString,
+    /// and MultiPolygon are not supported.
+    ///
+    /// ## Example
+    ///
+    /// Convert a GeoJSON object to a ReQL geometry object.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.geojson(json!({"type": "Point", "coordinates": [-122.423246, 37.779388]}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [to_geojson]
+    /// - [r.point]
+    ///
+    /// [to_geojson]: struct.Client.html#method.to_geojson
+    /// [r.point]: struct.r.html#method.point
+    /// [GeoJSON]: http://geojson.org
+    pub fn geojson<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 157, arg.into())
+    }
+}
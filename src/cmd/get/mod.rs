@@ -0,0 +1,50 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Get a document by primary key
+    ///
+    /// If no document exists with that primary key, `get` will return `null`.
+    ///
+    /// ## Example
+    ///
+    /// Find a document by UUID.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("posts").get("a9849eef-7176-4411-935b-79a6e3c56a74")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Find a document and merge another document with it.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("heroes").get(3).merge(json!({"powers": ["invisibility", "speed"]}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get_all]
+    /// - [between]
+    /// - [filter]
+    ///
+    /// [get_all]: struct.Client.html#method.get_all
+    /// [between]: struct.Client.html#method.between
+    /// [filter]: struct.Client.html#method.filter
+    pub fn get<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 16, arg.into())
+    }
+}
@@ -0,0 +1,53 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Get all documents where the given value matches the value of the
+    /// requested index
+    ///
+    /// To look up more than one key at a time, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// Secondary index keys are not guaranteed to be unique so we cannot
+    /// query via [get] when using a secondary index.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::get_all::Opts};
+    /// #
+    /// let opts = Opts::builder().index("code_name").build();
+    /// r.table("marvel").get_all(("man_of_steel", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Get all documents with the primary keys `1`, `2` and `3`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get_all(r.args([1, 2, 3]))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get]
+    /// - [between]
+    ///
+    /// [r.args]: struct.r.html#method.args
+    /// [get]: struct.Client.html#method.get
+    /// [between]: struct.Client.html#method.between
+    pub fn get_all<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 78, arg.into())
+    }
+}
@@ -0,0 +1,25 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<&'a str>,
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// The secondary index to look the keys up in (default: the primary key)
+    pub fn index(&mut self, index: &'a str) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
@@ -0,0 +1,37 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Get a single field from an object
+    ///
+    /// If called on a sequence, gets that field from every object in the sequence,
+    /// skipping objects that lack it.
+    ///
+    /// ## Example
+    ///
+    /// What was Iron Man's first appearance in a comic?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").get_field("first_appearance")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [bracket]
+    /// - [pluck]
+    ///
+    /// [bracket]: struct.Client.html#method.bracket
+    /// [pluck]: struct.Client.html#method.pluck
+    pub fn get_field<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 31, arg.into())
+    }
+}
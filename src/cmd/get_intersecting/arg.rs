@@ -0,0 +1,42 @@
+use {
+    super::Opts,
+    crate::cmd::{Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<'a, T> From<(T, Opts<'a>)> for Arg
+where
+    T: IntoArg,
+{
+    fn from((geometry, opts): (T, Opts<'a>)) -> Self {
+        Arg {
+            arg: geometry.into_arg(),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
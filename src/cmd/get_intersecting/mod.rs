@@ -0,0 +1,43 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Get all documents where the given geometry object intersects the
+    /// geometry object of the requested geospatial index
+    ///
+    /// Accepts a `(geometry, opts)` tuple, where the index in [Opts] is
+    /// required.
+    ///
+    /// ## Example
+    ///
+    /// Which of the locations in a list of parks intersect a given circle?
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::get_intersecting::Opts};
+    /// #
+    /// let circle = r.circle(([-117.220406, 32.719464], 10));
+    /// let opts = Opts::builder().index("area").build();
+    /// r.table("parks").get_intersecting((circle, opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get_nearest]
+    /// - [intersects]
+    ///
+    /// [Opts]: cmd/get_intersecting/struct.Opts.html
+    /// [get_nearest]: struct.Client.html#method.get_nearest
+    /// [intersects]: struct.Client.html#method.intersects
+    pub fn get_intersecting<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 166, arg.into())
+    }
+}
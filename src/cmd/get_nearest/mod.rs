@@ -0,0 +1,64 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Return a list of documents closest to a specified point based on a
+    /// geospatial index, sorted in order of increasing distance
+    ///
+    /// Accepts a `(point, opts)` tuple, where the index in [Opts] is
+    /// required. The return value will be an array of two-item objects with
+    /// the keys `dist` and `doc`, set to the distance between the specified
+    /// point and the document (in the units specified with `unit`, defaulting
+    /// to meters) and the document itself, respectively.
+    ///
+    /// ## Example
+    ///
+    /// Return a list of the closest 25 enemy hideouts to the secret base.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::get_nearest::Opts};
+    /// #
+    /// let secret_base = r.point((-122.422876, 37.777128));
+    /// let opts = Opts::builder().index("location").max_results(25).build();
+    /// r.table("hideouts").get_nearest((secret_base, opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get_intersecting]
+    /// - [distance]
+    ///
+    /// [Opts]: cmd/get_nearest/struct.Opts.html
+    /// [get_intersecting]: struct.Client.html#method.get_intersecting
+    /// [distance]: struct.Client.html#method.distance
+    pub fn get_nearest<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 168, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::r};
+
+    #[test]
+    fn options_are_serialised() {
+        let opts = Opts::builder()
+            .index("location")
+            .max_dist(5.0)
+            .unit(Unit::Kilometer)
+            .geo_system(GeoSystem::UnitSphere)
+            .build();
+        let query = r.table("hideouts").get_nearest(([-122.4, 37.7], opts));
+        let expected = r#"[168,[[15,["hideouts"]],[2,[-122.4,37.7]]],{"index":"location","max_dist":5.0,"unit":"km","geo_system":"unit_sphere"}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,59 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+pub use crate::cmd::circle::{GeoSystem, Unit};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_results: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_dist: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<Unit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    geo_system: Option<GeoSystem>,
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// The geospatial index to use (required)
+    pub fn index(&mut self, index: &'a str) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// The maximum number of results to return (default `100`)
+    pub fn max_results(&mut self, max_results: usize) -> &mut Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// The maximum distance from the point, in `unit`s (default `100000`)
+    pub fn max_dist(&mut self, max_dist: f64) -> &mut Self {
+        self.max_dist = Some(max_dist);
+        self
+    }
+
+    /// The unit of `max_dist` and of the returned distances (default `m`)
+    pub fn unit(&mut self, unit: Unit) -> &mut Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// The reference ellipsoid to use (default `WGS84`)
+    pub fn geo_system(&mut self, geo_system: GeoSystem) -> &mut Self {
+        self.geo_system = Some(geo_system);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
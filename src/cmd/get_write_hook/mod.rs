@@ -0,0 +1,30 @@
+use crate::Client;
+
+impl Client {
+    /// Get the write hook of a table
+    ///
+    /// The result can be deserialised into an `Option<`[WriteHook]`>`, holding
+    /// the hook's function in binary form and the query that set it, or
+    /// `None` if the table has no write hook.
+    ///
+    /// ## Example
+    ///
+    /// Get the write hook of the `comments` table.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("comments").get_write_hook()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [set_write_hook]
+    ///
+    /// [WriteHook]: https://docs.rs/reql-types/*/reql_types/struct.WriteHook.html
+    /// [set_write_hook]: struct.Client.html#method.set_write_hook
+    pub fn get_write_hook(&self) -> Client {
+        Client::new(&self.0, 190, ())
+    }
+}
@@ -0,0 +1,42 @@
+use {
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<U, P> From<(U, P)> for Arg
+where
+    U: IntoArg,
+    P: IntoArg,
+{
+    fn from((user, permissions): (U, P)) -> Self {
+        Arg {
+            arg: join_args(&[&user.into_arg(), &permissions.into_arg()]),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,74 @@
+mod arg;
+
+use {
+    crate::{r, Client},
+    bytes::Bytes,
+};
+
+pub use arg::Arg;
+
+fn grant(prev: &Bytes, arg: Arg) -> Client {
+    Client::new(prev, 188, arg)
+}
+
+impl r {
+    /// Grant or deny access permissions for a user account globally
+    ///
+    /// The argument is a tuple of the user name and an object of
+    /// permissions. Permissions are `read`, `write`, `connect` and `config`;
+    /// each can be set to `true` to grant it, `false` to deny it or `null` to
+    /// remove it, inheriting the permission from a higher scope. The result
+    /// can be deserialised into a [GrantStatus].
+    ///
+    /// ## Example
+    ///
+    /// Grant `bob` read access to everything.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.grant(("bob", json!({"read": true})))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [config]
+    ///
+    /// [GrantStatus]: https://docs.rs/reql-types/*/reql_types/struct.GrantStatus.html
+    /// [config]: struct.Client.html#method.config
+    pub fn grant<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        grant(&Bytes::new(), arg.into())
+    }
+}
+
+impl Client {
+    /// Grant or deny access permissions for a user account on a database or
+    /// table
+    ///
+    /// See [r.grant] for details.
+    ///
+    /// ## Example
+    ///
+    /// Deny `bob` write access to the `marvel` table.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.db("test").table("marvel").grant(("bob", json!({"write": false})))
+    /// # ;
+    /// ```
+    ///
+    /// [r.grant]: struct.r.html#method.grant
+    pub fn grant<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        grant(&self.0, arg.into())
+    }
+}
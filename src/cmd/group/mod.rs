@@ -0,0 +1,85 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Group elements by the values of the given field or by the results of
+    /// a function
+    ///
+    /// To group by more than one field, pass them using [r.args]. Grouping by
+    /// a secondary index is done through [Opts].
+    ///
+    /// `group` returns grouped data, which commands chained after it, such as
+    /// [count], [sum] or [max], operate on a per group basis. Use [ungroup]
+    /// to turn it into an array of objects instead. When a query returning
+    /// grouped data is run, the result can be deserialised into a
+    /// [Grouped] list of key/value pairs.
+    ///
+    /// ## Example
+    ///
+    /// What is each player's best game?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("games").group("player").max("points")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// How many games were played with each abilities combination?
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::group::Opts};
+    /// #
+    /// let opts = Opts::builder().multi(true).build();
+    /// r.table("games").group(("abilities", opts)).count(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Group the games by the `player` secondary index.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::group::Opts};
+    /// #
+    /// let opts = Opts::builder().index("player").build();
+    /// r.table("games").group(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [ungroup]
+    /// - [map]
+    /// - [reduce]
+    /// - [count]
+    /// - [sum]
+    /// - [avg]
+    /// - [min]
+    /// - [max]
+    ///
+    /// [r.args]: struct.r.html#method.args
+    /// [Opts]: cmd/group/struct.Opts.html
+    /// [Grouped]: https://docs.rs/reql-types/*/reql_types/struct.Grouped.html
+    /// [ungroup]: struct.Client.html#method.ungroup
+    /// [map]: struct.Client.html#method.map
+    /// [reduce]: struct.Client.html#method.reduce
+    /// [count]: struct.Client.html#method.count
+    /// [sum]: struct.Client.html#method.sum
+    /// [avg]: struct.Client.html#method.avg
+    /// [min]: struct.Client.html#method.min
+    /// [max]: struct.Client.html#method.max
+    pub fn group<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 144, arg.into())
+    }
+}
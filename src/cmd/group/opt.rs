@@ -0,0 +1,34 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    multi: Option<bool>,
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// Group by the values of a secondary index
+    pub fn index(&mut self, index: &'a str) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Put an element in one group per element of the array it's grouped
+    /// by, instead of a single group for the whole array (default `false`)
+    pub fn multi(&mut self, multi: bool) -> &mut Self {
+        self.multi = Some(multi);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
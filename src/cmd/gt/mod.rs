@@ -0,0 +1,41 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compare values, testing if the left-hand value is greater than the
+    /// right-hand
+    ///
+    /// To compare more than two values, pass them using [r.args]; they are tested
+    /// pairwise from left to right.
+    ///
+    /// ## Example
+    ///
+    /// Test if a player has scored more than 10 points.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("players").get(1).get_field("score").gt(10)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [ge]
+    /// - [lt]
+    /// - [le]
+    ///
+    /// [ge]: struct.Client.html#method.ge
+    /// [lt]: struct.Client.html#method.lt
+    /// [le]: struct.Client.html#method.le
+    /// [r.args]: struct.r.html#method.args
+    pub fn gt<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 21, arg.into())
+    }
+}
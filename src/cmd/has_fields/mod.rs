@@ -0,0 +1,50 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Test if an object has one or more fields
+    ///
+    /// An object has a field if it has that key and the key has a non-null value.
+    /// When called on a sequence, return the elements that have all the given
+    /// fields. To test for more than one field, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// Return the players who have won games.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("players").has_fields("games_won")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Which heroes are both married and have a sidekick?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").has_fields(r.args(["spouse", "sidekick"]))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get_field]
+    /// - [with_fields]
+    ///
+    /// [r.args]: struct.r.html#method.args
+    /// [get_field]: struct.Client.html#method.get_field
+    /// [with_fields]: struct.Client.html#method.with_fields
+    pub fn has_fields<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 32, arg.into())
+    }
+}
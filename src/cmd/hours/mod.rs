@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Return the hour in a time object as a number between 0 and 23
+    ///
+    /// ## Example
+    ///
+    /// Return all the posts submitted after midnight and before 4am.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("posts").filter(|post: Client| post.get_field("date").hours().lt(4))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [minutes]
+    /// - [seconds]
+    ///
+    /// [minutes]: struct.Client.html#method.minutes
+    /// [seconds]: struct.Client.html#method.seconds
+    pub fn hours(&self) -> Client {
+        Client::new(&self.0, 133, ())
+    }
+}
@@ -0,0 +1,41 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Return a new time object with a different timezone
+    ///
+    /// While the time stays the same, the results returned by methods such as
+    /// [hours] will change since they take the timezone into account. The timezone
+    /// argument has to be of the ISO 8601 format.
+    ///
+    /// ## Example
+    ///
+    /// Hour of the day in San Francisco (UTC/GMT -8, without daylight saving time).
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.now().in_timezone("-08:00").hours()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [timezone]
+    /// - [r.now]
+    /// - [r.time]
+    ///
+    /// [timezone]: struct.Client.html#method.timezone
+    /// [r.now]: struct.r.html#method.now
+    /// [r.time]: struct.r.html#method.time
+    /// [hours]: struct.Client.html#method.hours
+    pub fn in_timezone<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 104, arg.into())
+    }
+}
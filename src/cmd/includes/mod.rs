@@ -0,0 +1,37 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Test whether a geometry object is completely contained within another
+    ///
+    /// When applied to a sequence of geometry objects, `includes` acts as a
+    /// [filter], returning a sequence of objects from the sequence that include the
+    /// argument.
+    ///
+    /// ## Example
+    ///
+    /// Is a point included within a 2000-meter circle?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.circle((r.point((-122.423246, 37.770378359)), 2000)).includes(r.point((-122.422876, 37.777128)))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [intersects]
+    ///
+    /// [intersects]: struct.Client.html#method.intersects
+    /// [filter]: struct.Client.html#method.filter
+    pub fn includes<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 164, arg.into())
+    }
+}
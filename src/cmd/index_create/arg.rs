@@ -0,0 +1,87 @@
+use {
+    super::Opts,
+    crate::{
+        cmd::{join_args, Args, Datum, IntoArg, NoArg, Param, WithOpts},
+        ser::to_vec,
+    },
+    bytes::Bytes,
+    std::marker::PhantomData,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg<K> {
+    arg: Bytes,
+    opts: Vec<u8>,
+    kind: PhantomData<K>,
+}
+
+impl<K> Arg<K> {
+    fn new(name: &str, func: Option<Bytes>, opts: Vec<u8>) -> Self {
+        let name = to_vec(name);
+        let arg = match func {
+            Some(func) => join_args(&[&name, &func]),
+            None => Bytes::from(name),
+        };
+        Arg {
+            arg,
+            opts,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Arg<NoArg> {
+    fn from(name: &'a str) -> Self {
+        Arg::new(name, None, Vec::new())
+    }
+}
+
+impl From<String> for Arg<NoArg> {
+    fn from(name: String) -> Self {
+        Arg::new(&name, None, Vec::new())
+    }
+}
+
+impl<'a> From<(&'a str, Opts)> for Arg<WithOpts<NoArg>> {
+    fn from((name, opts): (&'a str, Opts)) -> Self {
+        Arg::new(name, None, opts.to_vec())
+    }
+}
+
+impl<'a, T, K> From<(&'a str, T)> for Arg<K>
+where
+    T: IntoArg<K>,
+{
+    fn from((name, func): (&'a str, T)) -> Self {
+        Arg::new(name, Some(func.into_arg()), Vec::new())
+    }
+}
+
+impl<'a, T, K> From<(&'a str, T, Opts)> for Arg<WithOpts<K>>
+where
+    T: IntoArg<K>,
+{
+    fn from((name, func, opts): (&'a str, T, Opts)) -> Self {
+        Arg::new(name, Some(func.into_arg()), opts.to_vec())
+    }
+}
+
+impl From<Args> for Arg<Datum> {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> Param for Arg<K> {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
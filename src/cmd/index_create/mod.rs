@@ -0,0 +1,87 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Create a new secondary index on a table
+    ///
+    /// Secondary indexes improve the speed of many read queries at the
+    /// slight cost of increased storage space and decreased write
+    /// performance. The result of the query can be deserialised into an
+    /// [IndexCreateStatus].
+    ///
+    /// The index can be built from a single field, from a term computing the
+    /// index value (such as a compound index, which returns an array), and it
+    /// can index every element of an array (`multi`) or geometry (`geo`).
+    ///
+    /// ## Example
+    ///
+    /// Create a simple index based on the field `post_id`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("comments").index_create("post_id")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Create a multi index on the `authors` array field.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::index_create::Opts};
+    /// #
+    /// let opts = Opts::builder().multi(true).build();
+    /// r.table("posts").index_create(("authors", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Create a geospatial index on the `location` field.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::index_create::Opts};
+    /// #
+    /// let opts = Opts::builder().geo(true).build();
+    /// r.table("places").index_create(("location", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Create an index based on the nested field `author.name`.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("comments").index_create(("author_name", |doc: Client| {
+    ///     doc.get_field("author").get_field("name")
+    /// }))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [index_wait]
+    /// - [index_status]
+    /// - [index_list]
+    /// - [index_drop]
+    ///
+    /// [IndexCreateStatus]: https://docs.rs/reql-types/*/reql_types/struct.IndexCreateStatus.html
+    /// [index_wait]: struct.Client.html#method.index_wait
+    /// [index_status]: struct.Client.html#method.index_status
+    /// [index_list]: struct.Client.html#method.index_list
+    /// [index_drop]: struct.Client.html#method.index_drop
+    pub fn index_create<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 75, arg.into())
+    }
+}
@@ -0,0 +1,33 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    multi: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    geo: Option<bool>,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// Index each element of an array field separately (default `false`)
+    pub fn multi(&mut self, multi: bool) -> &mut Self {
+        self.multi = Some(multi);
+        self
+    }
+
+    /// Create a geospatial index on a geometry field (default `false`)
+    pub fn geo(&mut self, geo: bool) -> &mut Self {
+        self.geo = Some(geo);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
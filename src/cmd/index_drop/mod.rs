@@ -0,0 +1,38 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Delete a previously created secondary index of this table
+    ///
+    /// The result of the query can be deserialised into an
+    /// [IndexDropStatus].
+    ///
+    /// ## Example
+    ///
+    /// Drop a secondary index named `code_name`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("dc").index_drop("code_name")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [index_create]
+    /// - [index_list]
+    ///
+    /// [IndexDropStatus]: https://docs.rs/reql-types/*/reql_types/struct.IndexDropStatus.html
+    /// [index_create]: struct.Client.html#method.index_create
+    /// [index_list]: struct.Client.html#method.index_list
+    pub fn index_drop<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 76, arg.into())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Client;
+
+impl Client {
+    /// List all the secondary indexes of this table
+    ///
+    /// The result is a list of strings, so it can be deserialised into a
+    /// `Vec<String>`.
+    ///
+    /// ## Example
+    ///
+    /// List the available secondary indexes for this table.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").index_list()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [index_create]
+    /// - [index_drop]
+    ///
+    /// [index_create]: struct.Client.html#method.index_create
+    /// [index_drop]: struct.Client.html#method.index_drop
+    pub fn index_list(&self) -> Client {
+        Client::new(&self.0, 77, ())
+    }
+}
@@ -0,0 +1,52 @@
+use {
+    super::Opts,
+    crate::{
+        cmd::{join_args, Args, Param},
+        ser::to_vec,
+    },
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<'a, 'b> From<(&'a str, &'b str)> for Arg {
+    fn from((old, new): (&'a str, &'b str)) -> Self {
+        Arg {
+            arg: join_args(&[&to_vec(old), &to_vec(new)]),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<'a, 'b> From<(&'a str, &'b str, Opts)> for Arg {
+    fn from((old, new, opts): (&'a str, &'b str, Opts)) -> Self {
+        let Arg { arg, .. } = (old, new).into();
+        Arg {
+            arg,
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,57 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Rename an existing secondary index on a table
+    ///
+    /// If the `overwrite` option is set and an index with the new name
+    /// already exists, it will be dropped first. The result of the query can
+    /// be deserialised into an [IndexRenameStatus].
+    ///
+    /// ## Example
+    ///
+    /// Rename an index on the comments table.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("comments").index_rename(("post_id", "message_id"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Rename an index, overwriting the one that already has that name.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::index_rename::Opts};
+    /// #
+    /// let opts = Opts::builder().overwrite(true).build();
+    /// r.table("users").index_rename(("mail", "email", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [index_create]
+    /// - [index_status]
+    /// - [index_wait]
+    /// - [index_drop]
+    ///
+    /// [IndexRenameStatus]: https://docs.rs/reql-types/*/reql_types/struct.IndexRenameStatus.html
+    /// [index_create]: struct.Client.html#method.index_create
+    /// [index_status]: struct.Client.html#method.index_status
+    /// [index_wait]: struct.Client.html#method.index_wait
+    /// [index_drop]: struct.Client.html#method.index_drop
+    pub fn index_rename<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 156, arg.into())
+    }
+}
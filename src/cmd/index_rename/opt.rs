@@ -0,0 +1,25 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overwrite: Option<bool>,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// Overwrite an existing index with the new name (default `false`)
+    pub fn overwrite(&mut self, overwrite: bool) -> &mut Self {
+        self.overwrite = Some(overwrite);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
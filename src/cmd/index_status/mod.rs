@@ -0,0 +1,48 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Get the status of the specified indexes on this table
+    ///
+    /// Pass `()` to get the status of all the indexes on the table. The
+    /// result is a list of objects which can be deserialised into a
+    /// `Vec<IndexStatus>`. An index is ready once its `ready` field is
+    /// `true`; until then `progress` reports how far along it is.
+    ///
+    /// ## Example
+    ///
+    /// Get the status of all the indexes on `test`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("test").index_status(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Get the status of the `timestamp` index.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("test").index_status("timestamp")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [index_wait]
+    ///
+    /// [index_wait]: struct.Client.html#method.index_wait
+    pub fn index_status<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 139, arg.into())
+    }
+}
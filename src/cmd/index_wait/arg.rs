@@ -0,0 +1,69 @@
+use {
+    crate::{
+        cmd::{Args, Param},
+        ser::to_vec,
+        Client,
+    },
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl From<()> for Arg {
+    fn from(_: ()) -> Self {
+        Arg {
+            arg: Bytes::new(),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Arg {
+    fn from(t: &'a str) -> Self {
+        Arg {
+            arg: Bytes::from(to_vec(t)),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<String> for Arg {
+    fn from(t: String) -> Self {
+        Arg {
+            arg: Bytes::from(to_vec(&t)),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Client> for Arg {
+    fn from(t: Client) -> Self {
+        Arg {
+            arg: t.0,
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,47 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Wait for the specified indexes on this table to be ready
+    ///
+    /// Pass `()` to wait for all the indexes on the table. The result is the
+    /// same list of objects returned by [index_status], which can be
+    /// deserialised into a `Vec<IndexStatus>`.
+    ///
+    /// ## Example
+    ///
+    /// Wait for all indexes on the table `test` to be ready.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("test").index_wait(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Wait for the index `timestamp` to be ready.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("test").index_wait("timestamp")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [index_status]
+    ///
+    /// [index_status]: struct.Client.html#method.index_status
+    pub fn index_wait<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 140, arg.into())
+    }
+}
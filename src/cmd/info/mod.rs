@@ -0,0 +1,25 @@
+use crate::Client;
+
+impl Client {
+    /// Get information about a ReQL value
+    ///
+    /// ## Example
+    ///
+    /// Get information about a table such as primary key, or cache size.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").info()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [type_of]
+    ///
+    /// [type_of]: struct.Client.html#method.type_of
+    pub fn info(&self) -> Client {
+        Client::new(&self.0, 79, ())
+    }
+}
@@ -0,0 +1,79 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Insert documents into a table
+    ///
+    /// Accepts a single document or an array of documents. The result of the
+    /// query can be deserialised into a [WriteStatus].
+    ///
+    /// ## Example
+    ///
+    /// Insert a document into the table `posts`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("posts").insert(json!({
+    ///     "id": 1,
+    ///     "title": "Lorem ipsum",
+    ///     "content": "Dolor sit amet",
+    /// }))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Insert a document, replacing the existing one if a document with the
+    /// same primary key already exists.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use reql::cmd::insert::{Conflict, Opts};
+    /// # use serde_json::json;
+    /// #
+    /// let opts = Opts::builder().conflict(Conflict::Replace).build();
+    /// r.table("posts").insert((json!({"id": 1, "title": "Lorem ipsum"}), opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [update]
+    /// - [replace]
+    /// - [delete]
+    ///
+    /// [WriteStatus]: https://docs.rs/reql-types/*/reql_types/struct.WriteStatus.html
+    /// [update]: struct.Client.html#method.update
+    /// [replace]: struct.Client.html#method.replace
+    /// [delete]: struct.Client.html#method.delete
+    pub fn insert<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 56, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::r, serde_json::json};
+
+    #[test]
+    fn options_are_serialised_after_arguments() {
+        let opts = Opts::builder()
+            .conflict(Conflict::Replace)
+            .return_changes(ReturnChanges::Always)
+            .build();
+        let query = r.table("posts").insert((json!({"id": 1}), opts));
+        let expected =
+            r#"[56,[[15,["posts"]],{"id":1}],{"return_changes":"always","conflict":"replace"}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
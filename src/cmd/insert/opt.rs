@@ -0,0 +1,101 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::{Serialize, Serializer},
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    durability: Option<Durability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    return_changes: Option<ReturnChanges>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conflict: Option<Conflict>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_write_hook: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Durability {
+    Hard,
+    Soft,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ReturnChanges {
+    Bool(bool),
+    Always,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Conflict {
+    Error,
+    Replace,
+    Update,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// Override the durability of the table or query (default `hard`)
+    ///
+    /// In soft durability mode RethinkDB will acknowledge the write
+    /// immediately after receiving and caching it, but before the write has
+    /// been committed to disk.
+    pub fn durability(&mut self, durability: Durability) -> &mut Self {
+        self.durability = Some(durability);
+        self
+    }
+
+    /// Return a `changes` array of `old_val`/`new_val` objects (default `false`)
+    ///
+    /// Pass `ReturnChanges::Always` to also get back the documents for which
+    /// nothing was changed.
+    pub fn return_changes<T>(&mut self, return_changes: T) -> &mut Self
+    where
+        T: Into<ReturnChanges>,
+    {
+        self.return_changes = Some(return_changes.into());
+        self
+    }
+
+    /// What to do when a document with the same primary key already exists
+    /// (default `error`)
+    pub fn conflict(&mut self, conflict: Conflict) -> &mut Self {
+        self.conflict = Some(conflict);
+        self
+    }
+
+    /// Skip the write hook of the table, if any (default `false`)
+    ///
+    /// This requires `config` permissions on the table.
+    pub fn ignore_write_hook(&mut self, ignore: bool) -> &mut Self {
+        self.ignore_write_hook = Some(ignore);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
+
+impl From<bool> for ReturnChanges {
+    fn from(return_changes: bool) -> Self {
+        ReturnChanges::Bool(return_changes)
+    }
+}
+
+impl Serialize for ReturnChanges {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ReturnChanges::Bool(value) => value.serialize(serializer),
+            ReturnChanges::Always => "always".serialize(serializer),
+        }
+    }
+}
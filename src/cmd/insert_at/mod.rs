@@ -0,0 +1,38 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Insert a value in to an array at a given index
+    ///
+    /// Accepts an `(index, value)` tuple. Returns the modified array.
+    ///
+    /// ## Example
+    ///
+    /// Hulk decides to join the avengers.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["Iron Man", "Spider-Man"]).insert_at((1, "Hulk"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [splice_at]
+    /// - [change_at]
+    /// - [delete_at]
+    ///
+    /// [splice_at]: struct.Client.html#method.splice_at
+    /// [change_at]: struct.Client.html#method.change_at
+    /// [delete_at]: struct.Client.html#method.delete_at
+    pub fn insert_at<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 82, arg.into())
+    }
+}
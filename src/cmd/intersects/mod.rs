@@ -0,0 +1,39 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Test whether two geometry objects intersect with one another
+    ///
+    /// When applied to a sequence of geometry objects, `intersects` acts as a
+    /// [filter], returning a sequence of objects from the sequence that intersect
+    /// with the argument.
+    ///
+    /// ## Example
+    ///
+    /// Is a point within a 2000-meter circle?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.circle((r.point((-122.423246, 37.770378359)), 2000)).intersects(r.point((-122.422876, 37.777128)))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [includes]
+    /// - [get_intersecting]
+    ///
+    /// [includes]: struct.Client.html#method.includes
+    /// [get_intersecting]: struct.Client.html#method.get_intersecting
+    /// [filter]: struct.Client.html#method.filter
+    pub fn intersects<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 163, arg.into())
+    }
+}
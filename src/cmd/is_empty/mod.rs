@@ -0,0 +1,25 @@
+use crate::Client;
+
+impl Client {
+    /// Test if a sequence is empty
+    ///
+    /// ## Example
+    ///
+    /// Are there any documents in the marvel table?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").is_empty()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [offsets_of]
+    ///
+    /// [offsets_of]: struct.Client.html#method.offsets_of
+    pub fn is_empty(&self) -> Client {
+        Client::new(&self.0, 86, ())
+    }
+}
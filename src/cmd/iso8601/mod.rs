@@ -0,0 +1,54 @@
+mod arg;
+mod opt;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl r {
+    /// Create a time object based on an ISO 8601 date-time string
+    ///
+    /// If the string doesn't contain a timezone, a default one has to be
+    /// passed through [Opts].
+    ///
+    /// ## Example
+    ///
+    /// Parse a time in Pacific Standard Time.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.iso8601("1986-11-03T08:30:00-07:00")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Parse a time without a timezone, treating it as UTC.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::iso8601::Opts};
+    /// #
+    /// let opts = Opts::builder().default_timezone("Z").build();
+    /// r.iso8601(("1986-11-03T08:30:00", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.now]
+    /// - [r.time]
+    /// - [to_iso8601]
+    ///
+    /// [Opts]: cmd/iso8601/struct.Opts.html
+    /// [r.now]: struct.r.html#method.now
+    /// [r.time]: struct.r.html#method.time
+    /// [to_iso8601]: struct.Client.html#method.to_iso8601
+    pub fn iso8601<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&[], 99, arg.into())
+    }
+}
@@ -0,0 +1,25 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_timezone: Option<&'a str>,
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// The timezone to use when the string doesn't contain one
+    pub fn default_timezone(&mut self, timezone: &'a str) -> &mut Self {
+        self.default_timezone = Some(timezone);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
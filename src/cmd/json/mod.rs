@@ -0,0 +1,34 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Parse a JSON string on the server
+    ///
+    /// ## Example
+    ///
+    /// Send an array to the server.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.json("[1,2,3]")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [to_json_string]
+    /// - [r.expr]
+    ///
+    /// [to_json_string]: struct.Client.html#method.to_json_string
+    /// [r.expr]: struct.r.html#method.expr
+    pub fn json<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 98, arg.into())
+    }
+}
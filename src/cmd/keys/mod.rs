@@ -0,0 +1,28 @@
+use crate::Client;
+
+impl Client {
+    /// Return an array containing all of an object's keys
+    ///
+    /// Note that the keys will be sorted as described in [ReQL data types].
+    ///
+    /// ## Example
+    ///
+    /// Get all the keys from a table row.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").get(1).keys()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [values]
+    ///
+    /// [ReQL data types]: https://rethinkdb.com/docs/data-types/#sorting-order
+    /// [values]: struct.Client.html#method.values
+    pub fn keys(&self) -> Client {
+        Client::new(&self.0, 94, ())
+    }
+}
@@ -0,0 +1,41 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compare values, testing if the left-hand value is less than or equal to
+    /// the right-hand
+    ///
+    /// To compare more than two values, pass them using [r.args]; they are tested
+    /// pairwise from left to right.
+    ///
+    /// ## Example
+    ///
+    /// Test if a player has scored 10 points or less.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("players").get(1).get_field("score").le(10)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [gt]
+    /// - [ge]
+    /// - [lt]
+    ///
+    /// [gt]: struct.Client.html#method.gt
+    /// [ge]: struct.Client.html#method.ge
+    /// [lt]: struct.Client.html#method.lt
+    /// [r.args]: struct.r.html#method.args
+    pub fn le<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 20, arg.into())
+    }
+}
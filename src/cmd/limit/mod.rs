@@ -0,0 +1,36 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// End the sequence after the given number of elements
+    ///
+    /// ## Example
+    ///
+    /// Only so many can fit in our Pantheon of heroes.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").order_by("belovedness").limit(10)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [skip]
+    /// - [slice]
+    /// - [nth]
+    ///
+    /// [skip]: struct.Client.html#method.skip
+    /// [slice]: struct.Client.html#method.slice
+    /// [nth]: struct.Client.html#method.nth
+    pub fn limit<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 71, arg.into())
+    }
+}
@@ -0,0 +1,55 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Construct a geometry object of type Line
+    ///
+    /// Accepts a vector of two or more points, either as points created with
+    /// [r.point] or as `[longitude, latitude]` pairs. The result can be
+    /// deserialised into a [Line].
+    ///
+    /// ## Example
+    ///
+    /// Define a line.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.line(vec![[-122.423246, 37.779388], [-121.886420, 37.329898]])
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Define a line using points.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.line(vec![
+    ///     r.point((-122.423246, 37.779388)),
+    ///     r.point((-121.886420, 37.329898)),
+    /// ])
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.point]
+    /// - [r.polygon]
+    /// - [fill]
+    ///
+    /// [Line]: https://docs.rs/reql-types/*/reql_types/struct.Line.html
+    /// [r.point]: struct.r.html#method.point
+    /// [r.polygon]: struct.r.html#method.polygon
+    /// [fill]: struct.Client.html#method.fill
+    pub fn line<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 160, arg.into())
+    }
+}
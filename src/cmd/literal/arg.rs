@@ -0,0 +1,60 @@
+use {
+    crate::{
+        cmd::{Args, IntoArg, Param},
+        Client,
+    },
+    bytes::Bytes,
+    serde_json::Value,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl Arg {
+    fn new(arg: Bytes) -> Self {
+        Arg {
+            arg,
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<()> for Arg {
+    fn from(_: ()) -> Self {
+        Arg::new(Bytes::new())
+    }
+}
+
+impl From<Value> for Arg {
+    fn from(object: Value) -> Self {
+        Arg::new(object.into_arg())
+    }
+}
+
+impl From<Client> for Arg {
+    fn from(object: Client) -> Self {
+        Arg::new(object.into_arg())
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
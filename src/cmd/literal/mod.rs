@@ -0,0 +1,52 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Replace an object in a field instead of merging it with an existing object
+    /// in a [merge] or [update] operation
+    ///
+    /// The object is passed as a `serde_json::Value` or as a query. Calling
+    /// `literal` with `()` in a merge or update removes the corresponding
+    /// field.
+    ///
+    /// ## Example
+    ///
+    /// Replace the nested `data` document with another one rather than
+    /// merging the fields.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.literal(json!({"age": 19, "job": "Engineer"}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Remove the corresponding field.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.literal(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [merge]
+    /// - [update]
+    ///
+    /// [merge]: struct.Client.html#method.merge
+    /// [update]: struct.Client.html#method.update
+    pub fn literal<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 137, arg.into())
+    }
+}
@@ -0,0 +1,41 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compare values, testing if the left-hand value is less than the
+    /// right-hand
+    ///
+    /// To compare more than two values, pass them using [r.args]; they are tested
+    /// pairwise from left to right.
+    ///
+    /// ## Example
+    ///
+    /// Test if a player has scored less than 10 points.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("players").get(1).get_field("score").lt(10)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [gt]
+    /// - [ge]
+    /// - [le]
+    ///
+    /// [gt]: struct.Client.html#method.gt
+    /// [ge]: struct.Client.html#method.ge
+    /// [le]: struct.Client.html#method.le
+    /// [r.args]: struct.r.html#method.args
+    pub fn lt<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 19, arg.into())
+    }
+}
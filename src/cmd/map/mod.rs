@@ -5,10 +5,85 @@ use crate::Client;
 pub use arg::Arg;
 
 impl Client {
-    pub fn map<A>(&self, arg: A) -> Client
+    /// Transform each element of one or more sequences by applying a mapping
+    /// function to them
+    ///
+    /// The function can be a Rust closure taking the element as a [Client],
+    /// which is compiled into a ReQL function. The closure's arguments need
+    /// a type annotation.
+    ///
+    /// ## Example
+    ///
+    /// Return the first five squares.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.expr([1, 2, 3, 4, 5]).map(|val: Client| val.clone() * val)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Get the names of all users.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("users").map(|user: Client| user.get_field("name"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [concat_map]
+    /// - [reduce]
+    /// - [fold]
+    ///
+    /// [Client]: struct.Client.html
+    /// [concat_map]: struct.Client.html#method.concat_map
+    /// [reduce]: struct.Client.html#method.reduce
+    /// [fold]: struct.Client.html#method.fold
+    pub fn map<A, K>(&self, arg: A) -> Client
     where
-        A: Into<Arg>,
+        A: Into<Arg<K>>,
     {
         Client::new(&self.0, 38, arg.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{r, Client},
+        serde_json::{json, Value},
+    };
+
+    // [69, [[2, [id]], body]]
+    fn func(term: &Value) -> (&Value, &Value) {
+        (&term[1][0][1][0], &term[1][1])
+    }
+
+    #[test]
+    fn closures_are_compiled_into_functions() {
+        let query = r.expr([1, 2]).map(|x: Client| x.add(1));
+        let term: Value = serde_json::from_slice(&query.0).unwrap();
+        assert_eq!(term[0], 38);
+        let (id, body) = func(&term[1][1]);
+        assert_eq!(body[0], 24);
+        assert_eq!(body[1][0], json!([10, [id]]));
+    }
+
+    #[test]
+    fn nested_closures_do_not_share_variables() {
+        let query = r
+            .expr([1, 2])
+            .map(|x: Client| r.expr([3, 4]).map(|y: Client| x.clone() + y));
+        let term: Value = serde_json::from_slice(&query.0).unwrap();
+        let (outer_id, outer_body) = func(&term[1][1]);
+        let (inner_id, inner_body) = func(&outer_body[1][1]);
+        assert_ne!(outer_id, inner_id);
+        assert_eq!(inner_body[1][0][1][0], *outer_id);
+        assert_eq!(inner_body[1][1][1][0], *inner_id);
+    }
+}
@@ -0,0 +1,62 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Match a string against a regular expression
+    ///
+    /// If there is a match, return an object with the fields:
+    ///
+    /// - `str`: the matched string
+    /// - `start`: the matched string's start
+    /// - `end`: the matched string's end
+    /// - `groups`: the capture groups defined with parentheses
+    ///
+    /// If no match is found, return `null`, so the result can be deserialised
+    /// into an `Option` of [Match].
+    ///
+    /// Accepts [RE2 syntax]. You can enable case-insensitive matching by prefixing
+    /// the regular expression with `(?i)`.
+    ///
+    /// ## Example
+    ///
+    /// Get all users whose name starts with "A".
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("users").filter(|doc: Client| doc.get_field("name").match_("^A"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Parse out a name.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr("id:0,name:mlucy,foo:bar").match_("name:(\\w+)")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [upcase]
+    /// - [downcase]
+    /// - [split]
+    ///
+    /// [Match]: https://docs.rs/reql-types/*/reql_types/struct.Match.html
+    /// [RE2 syntax]: https://github.com/google/re2/wiki/Syntax
+    /// [upcase]: struct.Client.html#method.upcase
+    /// [downcase]: struct.Client.html#method.downcase
+    /// [split]: struct.Client.html#method.split
+    pub fn match_<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 97, arg.into())
+    }
+}
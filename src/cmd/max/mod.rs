@@ -0,0 +1,66 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Find the maximum element in a sequence
+    ///
+    /// If called with a field name, find the element with the largest value
+    /// in that field. If called with a function, call it on every element of
+    /// the sequence and return the element which produced the largest
+    /// value. On a table, a secondary index can be used through [Opts].
+    ///
+    /// ## Example
+    ///
+    /// Return the maximum value in the list `[3, 5, 7]`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr([3, 5, 7]).max(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Return the user who has scored the most points.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").max("points")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Return the user with the largest value of the `points` secondary
+    /// index.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::max::Opts};
+    /// #
+    /// let opts = Opts::builder().index("points").build();
+    /// r.table("users").max(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [min]
+    /// - [group]
+    ///
+    /// [Opts]: cmd/max/struct.Opts.html
+    /// [min]: struct.Client.html#method.min
+    /// [group]: struct.Client.html#method.group
+    pub fn max<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 148, arg.into())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{r, Client};
+
+impl r {
+    /// The special constant that is larger than every other value
+    ///
+    /// It is used with [between] to express a range that is unbounded on one
+    /// side.
+    ///
+    /// ## Example
+    ///
+    /// Find all users with primary key >= 10.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").between((10, r.maxval()))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [between]
+    /// - [minval]
+    ///
+    /// [between]: struct.Client.html#method.between
+    /// [minval]: struct.r.html#method.minval
+    pub fn maxval(&self) -> Client {
+        Client::new(&[], 181, ())
+    }
+}
@@ -5,9 +5,9 @@ use crate::Client;
 pub use arg::Arg;
 
 impl Client {
-    pub fn merge<A>(&self, arg: A) -> Client
+    pub fn merge<A, K>(&self, arg: A) -> Client
     where
-        A: Into<Arg>,
+        A: Into<Arg<K>>,
     {
         Client::new(&self.0, 35, arg.into())
     }
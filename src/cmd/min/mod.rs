@@ -0,0 +1,66 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Find the minimum element in a sequence
+    ///
+    /// If called with a field name, find the element with the smallest value
+    /// in that field. If called with a function, call it on every element of
+    /// the sequence and return the element which produced the smallest
+    /// value. On a table, a secondary index can be used through [Opts].
+    ///
+    /// ## Example
+    ///
+    /// Return the minimum value in the list `[3, 5, 7]`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr([3, 5, 7]).min(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Return the user who has scored the fewest points.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").min("points")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Return the user with the smallest value of the `points` secondary
+    /// index.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::min::Opts};
+    /// #
+    /// let opts = Opts::builder().index("points").build();
+    /// r.table("users").min(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [max]
+    /// - [group]
+    ///
+    /// [Opts]: cmd/min/struct.Opts.html
+    /// [max]: struct.Client.html#method.max
+    /// [group]: struct.Client.html#method.group
+    pub fn min<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 147, arg.into())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Return the minute in a time object as a number between 0 and 59
+    ///
+    /// ## Example
+    ///
+    /// Return all the posts submitted during the first 10 minutes of every hour.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("posts").filter(|post: Client| post.get_field("date").minutes().lt(10))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [hours]
+    /// - [seconds]
+    ///
+    /// [hours]: struct.Client.html#method.hours
+    /// [seconds]: struct.Client.html#method.seconds
+    pub fn minutes(&self) -> Client {
+        Client::new(&self.0, 134, ())
+    }
+}
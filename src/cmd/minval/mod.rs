@@ -0,0 +1,30 @@
+use crate::{r, Client};
+
+impl r {
+    /// The special constant that is smaller than every other value
+    ///
+    /// It is used with [between] to express a range that is unbounded on one
+    /// side.
+    ///
+    /// ## Example
+    ///
+    /// Find all users with primary key < 20.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").between((r.minval(), 20))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [between]
+    /// - [maxval]
+    ///
+    /// [between]: struct.Client.html#method.between
+    /// [maxval]: struct.r.html#method.maxval
+    pub fn minval(&self) -> Client {
+        Client::new(&[], 180, ())
+    }
+}
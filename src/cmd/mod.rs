@@ -1,16 +1,173 @@
 //! ReQL command reference
 
+pub mod add;
+pub mod and;
+pub mod append;
+pub mod args;
+pub mod asc;
+pub mod avg;
+pub mod between;
+pub mod binary;
+pub mod bit_and;
+pub mod bit_not;
+pub mod bit_or;
+pub mod bit_sal;
+pub mod bit_sar;
+pub mod bit_xor;
+pub mod bracket;
+pub mod branch;
+pub mod ceil;
+pub mod change_at;
+pub mod changes;
+pub mod circle;
+pub mod coerce_to;
+pub mod concat_map;
+pub mod config;
 pub mod connect;
+pub mod contains;
+pub mod count;
+pub mod date;
+pub mod day;
 pub mod db;
+pub mod db_create;
+pub mod db_drop;
+pub mod db_list;
+pub mod default;
+pub mod delete;
+pub mod delete_at;
+pub mod desc;
+pub mod difference;
+pub mod distance;
+pub mod distinct;
+pub mod div;
+pub mod do_;
+pub mod downcase;
+pub mod during;
+pub mod epoch_time;
+pub mod eq;
+pub mod eq_join;
+pub mod error;
 pub mod expr;
+pub mod fill;
+pub mod filter;
+pub mod floor;
+pub mod fold;
+pub mod for_each;
+pub mod ge;
+pub mod geojson;
+pub mod get;
+pub mod get_all;
+pub mod get_field;
+pub mod get_intersecting;
+pub mod get_nearest;
+pub mod get_write_hook;
+pub mod grant;
+pub mod group;
+pub mod gt;
+pub mod has_fields;
+pub mod hours;
+pub mod in_timezone;
+pub mod includes;
+pub mod index_create;
+pub mod index_drop;
+pub mod index_list;
+pub mod index_rename;
+pub mod index_status;
+pub mod index_wait;
+pub mod info;
+pub mod inner_join;
+pub mod insert;
+pub mod insert_at;
+pub mod intersects;
+pub mod is_empty;
+pub mod iso8601;
+pub mod json;
+pub mod keys;
+pub mod le;
+pub mod limit;
+pub mod line;
+pub mod literal;
+pub mod lt;
 pub mod map;
+pub mod match_;
+pub mod max;
+pub mod maxval;
 pub mod merge;
+pub mod min;
+pub mod minutes;
+pub mod minval;
+pub mod mod_;
+pub mod month;
+pub mod mul;
+pub mod ne;
+pub mod not;
+pub mod now;
+pub mod nth;
+pub mod object;
+pub mod offsets_of;
+pub mod or;
+pub mod order_by;
+pub mod outer_join;
+pub mod pluck;
+pub mod point;
+pub mod polygon;
+pub mod polygon_sub;
+pub mod prepend;
+pub mod random;
+pub mod range;
+pub mod rebalance;
+pub mod reconfigure;
+pub mod reduce;
+pub mod replace;
+pub mod round;
 pub mod run;
+pub mod sample;
+pub mod seconds;
+pub mod set_difference;
+pub mod set_insert;
+pub mod set_intersection;
+pub mod set_union;
+pub mod set_write_hook;
+pub mod skip;
+pub mod slice;
+pub mod splice_at;
+pub mod split;
+pub mod status;
+pub mod sub;
+pub mod sum;
+pub mod sync;
 pub mod table;
+pub mod table_create;
+pub mod table_drop;
+pub mod table_list;
+pub mod time;
+pub mod time_of_day;
+pub mod timezone;
+pub mod to_epoch_time;
+pub mod to_geojson;
+pub mod to_iso8601;
+pub mod to_json_string;
+pub mod type_of;
+pub mod ungroup;
+pub mod union;
+pub mod upcase;
+pub mod update;
+pub mod uuid;
+pub mod values;
+pub mod wait;
+pub mod with_fields;
+pub mod without;
+pub mod year;
+pub mod zip;
 
 use {
-    crate::Client,
+    crate::{ser::to_vec, Client},
     bytes::{BufMut, Bytes, BytesMut},
+    serde::Serialize,
+    std::{
+        marker::PhantomData,
+        sync::atomic::{AtomicU64, Ordering::SeqCst},
+    },
 };
 
 #[doc(hidden)]
@@ -26,9 +183,191 @@ macro make_builder() {
     }
 }
 
+/// A value that can be passed as an argument to a command
+///
+/// This is implemented for anything that can be serialised into JSON, for
+/// other queries and for closures taking one to three `Client` arguments,
+/// which are compiled into ReQL functions. The type parameter only exists to
+/// keep the different kinds of arguments apart.
+pub trait IntoArg<K = Datum> {
+    #[doc(hidden)]
+    fn into_arg(self) -> Bytes;
+}
+
+/// Marks an argument that is a datum or a query
+#[derive(Debug, Clone, Copy)]
+pub enum Datum {}
+
+/// Marks the absence of an argument, for commands where it's optional
+#[derive(Debug, Clone, Copy)]
+pub enum NoArg {}
+
+/// Marks an argument that is a closure taking `A` as its arguments
+#[derive(Debug, Clone, Copy)]
+pub struct Func<A>(PhantomData<A>);
+
+/// Marks an argument that is followed by the command's options
+#[derive(Debug, Clone, Copy)]
+pub struct WithOpts<K>(PhantomData<K>);
+
+impl<T: Serialize> IntoArg for T {
+    fn into_arg(self) -> Bytes {
+        Bytes::from(to_vec(&self))
+    }
+}
+
+impl IntoArg for Client {
+    fn into_arg(self) -> Bytes {
+        self.0
+    }
+}
+
+impl<F> IntoArg<Func<(Client,)>> for F
+where
+    F: Fn(Client) -> Client,
+{
+    fn into_arg(self) -> Bytes {
+        let (a, x) = var();
+        func(&[a], self(x))
+    }
+}
+
+impl<F> IntoArg<Func<(Client, Client)>> for F
+where
+    F: Fn(Client, Client) -> Client,
+{
+    fn into_arg(self) -> Bytes {
+        let ((a, x), (b, y)) = (var(), var());
+        func(&[a, b], self(x, y))
+    }
+}
+
+impl<F> IntoArg<Func<(Client, Client, Client)>> for F
+where
+    F: Fn(Client, Client, Client) -> Client,
+{
+    fn into_arg(self) -> Bytes {
+        let ((a, x), (b, y), (c, z)) = (var(), var(), var());
+        func(&[a, b, c], self(x, y, z))
+    }
+}
+
+// Variables are numbered globally rather than per closure so that the
+// arguments of nested closures never shadow each other
+static VAR_ID: AtomicU64 = AtomicU64::new(1);
+
+// Creates a new `VAR` term, returning its id along with it
+fn var() -> (u64, Client) {
+    let id = VAR_ID.fetch_add(1, SeqCst);
+    let var = format!("[10,[{}]]", id);
+    (id, Client(Bytes::from(var)))
+}
+
+// Wraps the body of a closure in a `FUNC` term taking the given variables
+fn func(ids: &[u64], body: Client) -> Bytes {
+    let ids: Vec<_> = ids.iter().map(u64::to_string).collect();
+    let params = format!("[2,[{}]]", ids.join(","));
+    let mut buf = BytesMut::with_capacity(params.len() + body.0.len() + 8);
+    buf.put("[69,[");
+    buf.put(params.as_str());
+    buf.put(",");
+    buf.put(&body.0);
+    buf.put("]]");
+    buf.freeze()
+}
+
+// Joins serialised arguments into the comma separated list used by `Command`
+fn join_args(args: &[&[u8]]) -> Bytes {
+    let len = args.iter().map(|arg| arg.len() + 1).sum();
+    let mut buf = BytesMut::with_capacity(len);
+    for (i, arg) in args.iter().enumerate() {
+        if i > 0 {
+            buf.put(",");
+        }
+        buf.put(*arg);
+    }
+    buf.freeze()
+}
+
+/// Arguments and options for a command, collected at runtime
+///
+/// Every command that takes arguments also accepts `Args` in their place,
+/// splicing them in as if they had been passed one by one. This is what the
+/// `args!` macro from the `reql-macros` crate expands to, but it can also be
+/// used directly when the number of arguments is only known at runtime.
+///
+/// ## Example
+///
+/// Get the heroes whose names are only known at runtime.
+///
+/// ```rust
+/// # use reql::{r, cmd::Args};
+/// #
+/// let names = vec!["Iron Man", "Spider-Man"];
+/// let mut args = Args::new();
+/// for name in names {
+///     args.add_arg(name);
+/// }
+/// args.add_opt("index", "name");
+/// r.table("marvel").get_all(args)
+/// # ;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    arg: BytesMut,
+    opts: Vec<u8>,
+}
+
+impl Args {
+    /// Create an empty list of arguments
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Append a positional argument
+    pub fn add_arg<T, K>(&mut self, arg: T) -> &mut Self
+    where
+        T: IntoArg<K>,
+    {
+        if !self.arg.is_empty() {
+            self.arg.extend_from_slice(b",");
+        }
+        self.arg.extend_from_slice(&arg.into_arg());
+        self
+    }
+
+    /// Add an option, the value of which may also be a query
+    pub fn add_opt<T, K>(&mut self, key: &str, value: T) -> &mut Self
+    where
+        T: IntoArg<K>,
+    {
+        // reopen the object if we already have one
+        match self.opts.pop() {
+            Some(_) => self.opts.push(b','),
+            None => self.opts.push(b'{'),
+        }
+        self.opts.extend(to_vec(key));
+        self.opts.push(b':');
+        self.opts.extend_from_slice(&value.into_arg());
+        self.opts.push(b'}');
+        self
+    }
+}
+
 trait Param {
-    fn arg(&self) -> &Bytes;
-    fn opts(&self) -> &Vec<u8>;
+    fn arg(&self) -> &[u8];
+    fn opts(&self) -> &[u8];
+}
+
+// For commands that don't take any arguments
+impl Param for () {
+    fn arg(&self) -> &[u8] {
+        &[]
+    }
+
+    fn opts(&self) -> &[u8] {
+        &[]
+    }
 }
 
 impl Client {
@@ -43,12 +382,12 @@ impl Client {
 struct Command<'a> {
     id: u16,
     prev: &'a [u8],
-    arg: &'a Bytes,
-    opts: &'a Vec<u8>,
+    arg: &'a [u8],
+    opts: &'a [u8],
 }
 
 impl<'a> Command<'a> {
-    fn new(prev: &'a [u8], id: u16, arg: &'a Bytes, opts: &'a Vec<u8>) -> Self {
+    fn new(prev: &'a [u8], id: u16, arg: &'a [u8], opts: &'a [u8]) -> Self {
         Self {
             prev,
             id,
@@ -60,19 +399,51 @@ impl<'a> Command<'a> {
 
 impl<'a> From<Command<'a>> for Client {
     fn from(this: Command<'a>) -> Client {
-        let (header, footer) = (format!("[{},[", this.id), "]]");
-        let len = header.len() + this.prev.len() + this.arg.len() + footer.len() + 2;
+        // [id,[prev,arg],opts]
+        let header = format!("[{},[", this.id);
+        let len = header.len() + this.prev.len() + this.arg.len() + this.opts.len() + 4;
         let mut cmd = BytesMut::with_capacity(len);
         cmd.put(header);
         cmd.put(this.prev);
-        if !this.prev.is_empty() {
+        if !this.prev.is_empty() && !this.arg.is_empty() {
             cmd.put(",");
         }
         cmd.put(this.arg);
-        if !this.opts.is_empty() && (!this.arg.is_empty() || !this.prev.is_empty()) {
+        cmd.put("]");
+        if !this.opts.is_empty() {
             cmd.put(",");
+            cmd.put(this.opts);
         }
-        cmd.put(footer);
+        cmd.put("]");
         Client(cmd.freeze())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::Args,
+        crate::{r, Client},
+    };
+
+    #[test]
+    fn args_are_spliced_into_commands() {
+        let mut args = Args::new();
+        args.add_arg("Iron Man")
+            .add_arg("Spider-Man")
+            .add_opt("index", "name");
+        let query = r.table("marvel").get_all(args);
+        let expected = r#"[78,[[15,["marvel"]],"Iron Man","Spider-Man"],{"index":"name"}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+
+    #[test]
+    fn closures_and_queries_are_spliced() {
+        let mut args = Args::new();
+        args.add_arg(|post: Client| post.get_field("published"))
+            .add_opt("default", r.expr(true));
+        let query = r.table("posts").filter(args);
+        let query = String::from_utf8(query.0.to_vec()).unwrap();
+        assert!(query.ends_with(r#"],{"default":true}]"#));
+    }
+}
@@ -0,0 +1,65 @@
+mod arg;
+
+use {
+    crate::{cmd::IntoArg, Client},
+    std::ops::Rem,
+};
+
+pub use arg::Arg;
+
+impl Client {
+    /// Find the remainder when dividing two numbers
+    ///
+    /// The `%` operator can be used as well.
+    ///
+    /// ## Example
+    ///
+    /// It's as easy as 23 % 4 = 3.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(23).mod_(4)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Use the operator instead.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(23) % 4
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [add]
+    /// - [sub]
+    /// - [mul]
+    /// - [div]
+    ///
+    /// [add]: struct.Client.html#method.add
+    /// [sub]: struct.Client.html#method.sub
+    /// [mul]: struct.Client.html#method.mul
+    /// [div]: struct.Client.html#method.div
+    pub fn mod_<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 28, arg.into())
+    }
+}
+
+impl<T> Rem<T> for Client
+where
+    T: IntoArg,
+{
+    type Output = Client;
+
+    fn rem(self, rhs: T) -> Client {
+        Client::mod_(&self, rhs)
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Return the month of a time object as a number between 1 and 12
+    ///
+    /// ## Example
+    ///
+    /// Retrieve all the users who were born in November.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("users").filter(|user: Client| user.get_field("birthdate").month().eq(11))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [year]
+    /// - [day]
+    ///
+    /// [year]: struct.Client.html#method.year
+    /// [day]: struct.Client.html#method.day
+    pub fn month(&self) -> Client {
+        Client::new(&self.0, 129, ())
+    }
+}
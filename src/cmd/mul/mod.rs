@@ -0,0 +1,65 @@
+mod arg;
+
+use {
+    crate::{cmd::IntoArg, Client},
+    std::ops::Mul,
+};
+
+pub use arg::Arg;
+
+impl Client {
+    /// Multiply two numbers, or make a periodic array
+    ///
+    /// The `*` operator can be used as well.
+    ///
+    /// ## Example
+    ///
+    /// It's as easy as 2 * 2 = 4.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(2).mul(2)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Arrays can be multiplied by numbers as well.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["This", "is", "the", "song", "that", "never", "ends."]) * 100
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [add]
+    /// - [sub]
+    /// - [div]
+    /// - [mod_]
+    ///
+    /// [add]: struct.Client.html#method.add
+    /// [sub]: struct.Client.html#method.sub
+    /// [div]: struct.Client.html#method.div
+    /// [mod_]: struct.Client.html#method.mod_
+    pub fn mul<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 26, arg.into())
+    }
+}
+
+impl<T> Mul<T> for Client
+where
+    T: IntoArg,
+{
+    type Output = Client;
+
+    fn mul(self, rhs: T) -> Client {
+        Client::mul(&self, rhs)
+    }
+}
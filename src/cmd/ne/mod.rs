@@ -0,0 +1,35 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Test if two or more values are not equal
+    ///
+    /// To compare more than two values, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// See if a user's `role` field is not set to `administrator`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").get(1).get_field("role").ne("administrator")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [eq]
+    ///
+    /// [eq]: struct.Client.html#method.eq
+    /// [r.args]: struct.r.html#method.args
+    pub fn ne<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 18, arg.into())
+    }
+}
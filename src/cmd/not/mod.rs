@@ -0,0 +1,48 @@
+use {crate::Client, std::ops::Not};
+
+impl Client {
+    /// Compute the logical inverse (not) of a value
+    ///
+    /// The `!` operator can be used as well.
+    ///
+    /// ## Example
+    ///
+    /// Not true is false.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(true).not()
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Use the operator instead.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// !r.expr(true)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [and]
+    /// - [or]
+    ///
+    /// [and]: struct.Client.html#method.and
+    /// [or]: struct.Client.html#method.or
+    pub fn not(&self) -> Client {
+        Client::new(&self.0, 23, ())
+    }
+}
+
+impl Not for Client {
+    type Output = Client;
+
+    fn not(self) -> Client {
+        Client::not(&self)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{r, Client};
+
+impl r {
+    /// Return a time object representing the current time in UTC
+    ///
+    /// The command `now` is computed once when the server receives the query, so
+    /// multiple instances of `r.now()` will always return the same time inside a
+    /// query. The result can be deserialised into a [DateTime].
+    ///
+    /// ## Example
+    ///
+    /// Get the current time.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.now()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.time]
+    /// - [r.epoch_time]
+    /// - [r.iso8601]
+    ///
+    /// [r.time]: struct.r.html#method.time
+    /// [r.epoch_time]: struct.r.html#method.epoch_time
+    /// [r.iso8601]: struct.r.html#method.iso8601
+    /// [DateTime]: https://docs.rs/reql-types/*/reql_types/struct.DateTime.html
+    pub fn now(&self) -> Client {
+        Client::new(&[], 103, ())
+    }
+}
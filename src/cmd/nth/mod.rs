@@ -0,0 +1,38 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Get the nth element of a sequence, counting from zero
+    ///
+    /// If the argument is negative, count from the last element.
+    ///
+    /// ## Example
+    ///
+    /// Select the second element in the array.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr([1, 2, 3]).nth(1)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [skip]
+    /// - [limit]
+    /// - [slice]
+    ///
+    /// [skip]: struct.Client.html#method.skip
+    /// [limit]: struct.Client.html#method.limit
+    /// [slice]: struct.Client.html#method.slice
+    pub fn nth<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 45, arg.into())
+    }
+}
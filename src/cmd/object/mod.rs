@@ -0,0 +1,39 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Create an object from a list of key/value pairs, where the keys must be
+    /// strings
+    ///
+    /// Pass the keys and values using [r.args]. `r.object(r.args(("a", 1, "b", 2)))`
+    /// is equivalent to the object `{"a": 1, "b": 2}`, except that the keys and
+    /// values can be other queries.
+    ///
+    /// ## Example
+    ///
+    /// Create a simple object.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.object(r.args(("id", 5, "data", ["foo", "bar"])))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.args]
+    /// - [coerce_to]
+    ///
+    /// [r.args]: struct.r.html#method.args
+    /// [coerce_to]: struct.Client.html#method.coerce_to
+    pub fn object<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 143, arg.into())
+    }
+}
@@ -0,0 +1,34 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Get the indexes of an element in a sequence
+    ///
+    /// If the argument is a predicate, get the indexes of all elements matching it.
+    ///
+    /// ## Example
+    ///
+    /// Find the position of the letter `c`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["a", "b", "c"]).offsets_of("c")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [is_empty]
+    ///
+    /// [is_empty]: struct.Client.html#method.is_empty
+    pub fn offsets_of<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 87, arg.into())
+    }
+}
@@ -0,0 +1,63 @@
+mod arg;
+
+use {
+    crate::{cmd::IntoArg, Client},
+    std::ops::BitOr,
+};
+
+pub use arg::Arg;
+
+impl Client {
+    /// Compute the logical "or" of one or more values
+    ///
+    /// The `|` operator can be used as well. To compute the "or" of more than two
+    /// values, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// Return whether either `a` or `b` evaluate to true.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(true).or(false)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Use the operator instead.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(true) | false
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [and]
+    /// - [not]
+    ///
+    /// [and]: struct.Client.html#method.and
+    /// [not]: struct.Client.html#method.not
+    /// [r.args]: struct.r.html#method.args
+    pub fn or<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 66, arg.into())
+    }
+}
+
+impl<T> BitOr<T> for Client
+where
+    T: IntoArg,
+{
+    type Output = Client;
+
+    fn bitor(self, rhs: T) -> Client {
+        Client::or(&self, rhs)
+    }
+}
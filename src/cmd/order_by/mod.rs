@@ -0,0 +1,89 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Sort the sequence by document values of the given key(s)
+    ///
+    /// To specify the ordering, wrap the key with [r.asc] or [r.desc]
+    /// (defaults to ascending). To order by more than one key, pass them using
+    /// [r.args], or [Args] if some of them are wrapped. Ordering by a
+    /// secondary index is done through [Opts], which is much faster on tables
+    /// and doesn't load the whole table into memory.
+    ///
+    /// ## Example
+    ///
+    /// Order all the posts using the index `date`.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::order_by::Opts};
+    /// #
+    /// let opts = Opts::builder().index("date").build();
+    /// r.table("posts").order_by(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Order the posts by the index `date` in descending order, then by
+    /// title.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::order_by::{Index, Opts}};
+    /// #
+    /// let opts = Opts::builder().index(Index::Desc("date")).build();
+    /// r.table("posts").order_by(("title", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Order heroes by their number of victories first, then by name.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::Args};
+    /// #
+    /// let mut keys = Args::new();
+    /// keys.add_arg(r.desc("victories")).add_arg(r.asc("name"));
+    /// r.table("marvel").order_by(keys)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [skip]
+    /// - [limit]
+    /// - [between]
+    ///
+    /// [r.asc]: struct.r.html#method.asc
+    /// [r.desc]: struct.r.html#method.desc
+    /// [r.args]: struct.r.html#method.args
+    /// [Args]: cmd/struct.Args.html
+    /// [Opts]: cmd/order_by/struct.Opts.html
+    /// [skip]: struct.Client.html#method.skip
+    /// [limit]: struct.Client.html#method.limit
+    /// [between]: struct.Client.html#method.between
+    pub fn order_by<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 41, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::r};
+
+    #[test]
+    fn descending_index_is_serialised_as_a_term() {
+        let opts = Opts::builder().index(Index::Desc("date")).build();
+        let query = r.table("posts").order_by(opts);
+        let expected = r#"[41,[[15,["posts"]]],{"index":[74,["date"]]}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{cmd::make_builder, r};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Opts<'a> {
+    index: Option<Index<'a>>,
+}
+
+/// The index to order by and the direction to order it in
+#[derive(Debug, Clone, Copy)]
+pub enum Index<'a> {
+    Asc(&'a str),
+    Desc(&'a str),
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// Order by a secondary index instead of fields of the documents
+    ///
+    /// Passing just the name of the index orders it in ascending order.
+    pub fn index<T>(&mut self, index: T) -> &mut Self
+    where
+        T: Into<Index<'a>>,
+    {
+        self.index = Some(index.into());
+        self
+    }
+
+    // The index may be wrapped in `r.asc` or `r.desc`, which are terms
+    // rather than datums, so this can't go through serde
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        let index = match self.index {
+            Some(Index::Asc(name)) => r.asc(name),
+            Some(Index::Desc(name)) => r.desc(name),
+            None => return Vec::new(),
+        };
+        let mut buf = b"{\"index\":".to_vec();
+        buf.extend_from_slice(&index.0);
+        buf.push(b'}');
+        buf
+    }
+}
+
+impl<'a> From<&'a str> for Index<'a> {
+    fn from(name: &'a str) -> Self {
+        Index::Asc(name)
+    }
+}
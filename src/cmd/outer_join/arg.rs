@@ -0,0 +1,46 @@
+use {
+    crate::cmd::{join_args, Args, Datum, IntoArg, Param},
+    bytes::Bytes,
+    std::marker::PhantomData,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg<K> {
+    arg: Bytes,
+    opts: Vec<u8>,
+    kind: PhantomData<K>,
+}
+
+impl<S, P, K> From<(S, P)> for Arg<K>
+where
+    S: IntoArg,
+    P: IntoArg<K>,
+{
+    fn from((sequence, predicate): (S, P)) -> Self {
+        Arg {
+            arg: join_args(&[&sequence.into_arg(), &predicate.into_arg()]),
+            opts: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl From<Args> for Arg<Datum> {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> Param for Arg<K> {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,49 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Return the pairs of rows of both sequences for which the predicate
+    /// returns true, keeping rows of the left sequence that have no match
+    ///
+    /// Accepts an `(other_sequence, predicate)` tuple, where the predicate is
+    /// a function taking a row of each sequence. Each result is an object
+    /// with a `left` and a `right` field, which can be deserialised into a
+    /// [Joined] value.
+    ///
+    /// Rows of the left sequence without a match don't have a `right` field,
+    /// so use an `Option` for it, as in `Joined<Hero, Option<Team>>`.
+    ///
+    /// Note that `outer_join` is slow and inefficient on large tables. Use
+    /// [eq_join] whenever possible.
+    ///
+    /// ## Example
+    ///
+    /// Return a list of all matchups between Marvel and DC heroes.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// # fn query(predicate: Client) -> Client {
+    /// r.table("marvel").outer_join((r.table("dc"), predicate))
+    /// # }
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [eq_join]
+    /// - [inner_join]
+    /// - [zip]
+    ///
+    /// [Joined]: https://docs.rs/reql-types/*/reql_types/struct.Joined.html
+    /// [eq_join]: struct.Client.html#method.eq_join
+    /// [inner_join]: struct.Client.html#method.inner_join
+    /// [zip]: struct.Client.html#method.zip
+    pub fn outer_join<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 49, arg.into())
+    }
+}
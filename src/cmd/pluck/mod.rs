@@ -0,0 +1,67 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Select only the given fields from an object or each object in a sequence
+    ///
+    /// To select more than one field, pass them using [r.args]. Nested fields can
+    /// be selected using an object, which works like `pluck` on the nested value.
+    ///
+    /// ## Example
+    ///
+    /// We just need information about IronMan's reactor and not the rest of the document.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").pluck(r.args(["reactor_state", "reactor_power"]))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// For the hero beauty contest we only care about certain qualities.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("marvel").pluck(json!({"abilities": {"damage": true, "mana": true}}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [without]
+    /// - [with_fields]
+    /// - [get_field]
+    ///
+    /// [r.args]: struct.r.html#method.args
+    /// [without]: struct.Client.html#method.without
+    /// [with_fields]: struct.Client.html#method.with_fields
+    /// [get_field]: struct.Client.html#method.get_field
+    pub fn pluck<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 33, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {crate::r, serde_json::json};
+
+    #[test]
+    fn nested_selectors_are_serialised_as_objects() {
+        let query = r
+            .table("marvel")
+            .pluck(json!({"abilities": {"damage": true}, "names": ["first"]}));
+        let expected =
+            r#"[33,[[15,["marvel"]],{"abilities":{"damage":true},"names":[2,["first"]]}]]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,42 @@
+use {
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<X, Y> From<(X, Y)> for Arg
+where
+    X: IntoArg,
+    Y: IntoArg,
+{
+    fn from((longitude, latitude): (X, Y)) -> Self {
+        Arg {
+            arg: join_args(&[&longitude.into_arg(), &latitude.into_arg()]),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,40 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Construct a geometry object of type Point
+    ///
+    /// Accepts a `(longitude, latitude)` tuple. The result can be
+    /// deserialised into a [Point].
+    ///
+    /// ## Example
+    ///
+    /// Define a point.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.point((-122.423246, 37.779388))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.line]
+    /// - [r.polygon]
+    /// - [r.circle]
+    ///
+    /// [Point]: https://docs.rs/reql-types/*/reql_types/struct.Point.html
+    /// [r.line]: struct.r.html#method.line
+    /// [r.polygon]: struct.r.html#method.polygon
+    /// [r.circle]: struct.r.html#method.circle
+    pub fn point<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 159, arg.into())
+    }
+}
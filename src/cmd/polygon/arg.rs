@@ -0,0 +1,43 @@
+use {
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<T> From<Vec<T>> for Arg
+where
+    T: IntoArg,
+{
+    fn from(points: Vec<T>) -> Self {
+        let points: Vec<_> = points.into_iter().map(|point| point.into_arg()).collect();
+        let points: Vec<_> = points.iter().map(|point| &point[..]).collect();
+        Arg {
+            arg: join_args(&points),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
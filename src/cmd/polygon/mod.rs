@@ -0,0 +1,48 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Construct a geometry object of type Polygon
+    ///
+    /// Accepts a vector of three or more points, either as points created
+    /// with [r.point] or as `[longitude, latitude]` pairs. If the last point
+    /// doesn't specify the same coordinates as the first point, the polygon
+    /// is closed by connecting them. The result can be deserialised into a
+    /// [Polygon].
+    ///
+    /// ## Example
+    ///
+    /// Define a polygon.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.polygon(vec![
+    ///     [-122.423246, 37.779388],
+    ///     [-122.423246, 37.329898],
+    ///     [-121.886420, 37.329898],
+    ///     [-121.886420, 37.779388],
+    /// ])
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.point]
+    /// - [r.line]
+    /// - [polygon_sub]
+    ///
+    /// [Polygon]: https://docs.rs/reql-types/*/reql_types/struct.Polygon.html
+    /// [r.point]: struct.r.html#method.point
+    /// [r.line]: struct.r.html#method.line
+    /// [polygon_sub]: struct.Client.html#method.polygon_sub
+    pub fn polygon<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 161, arg.into())
+    }
+}
@@ -0,0 +1,38 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Use a polygon to "punch out" a hole in another polygon
+    ///
+    /// The argument must be completely contained within the polygon it's called on
+    /// and must not intersect with its outer boundary. Returns a polygon with the
+    /// given one as a hole.
+    ///
+    /// ## Example
+    ///
+    /// Define a polygon with a hole punched in it.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// let outer = r.polygon(vec![[-122.4, 37.7], [-122.4, 37.3], [-121.8, 37.3], [-121.8, 37.7]]);
+    /// let inner = r.polygon(vec![[-122.3, 37.4], [-122.3, 37.6], [-122.0, 37.6], [-122.0, 37.4]]);
+    /// outer.polygon_sub(inner)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.polygon]
+    ///
+    /// [r.polygon]: struct.r.html#method.polygon
+    pub fn polygon_sub<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 171, arg.into())
+    }
+}
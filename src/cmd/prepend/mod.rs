@@ -0,0 +1,34 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Prepend a value to an array
+    ///
+    /// ## Example
+    ///
+    /// Retrieve Iron Man's equipment list with the addition of some new boots.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").get_field("equipment").prepend("new_boots")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [append]
+    /// - [insert_at]
+    ///
+    /// [append]: struct.Client.html#method.append
+    /// [insert_at]: struct.Client.html#method.insert_at
+    pub fn prepend<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 80, arg.into())
+    }
+}
@@ -0,0 +1,94 @@
+use {
+    super::Opts,
+    crate::{
+        cmd::{join_args, Args, IntoArg, Param},
+        Client,
+    },
+    bytes::Bytes,
+    std::ops::{Range, RangeTo},
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl Arg {
+    fn new(arg: Bytes) -> Self {
+        Arg {
+            arg,
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<()> for Arg {
+    fn from(_: ()) -> Self {
+        Arg::new(Bytes::new())
+    }
+}
+
+impl<T> From<RangeTo<T>> for Arg
+where
+    T: IntoArg,
+{
+    fn from(range: RangeTo<T>) -> Self {
+        Arg::new(range.end.into_arg())
+    }
+}
+
+impl<T> From<Range<T>> for Arg
+where
+    T: IntoArg,
+{
+    fn from(range: Range<T>) -> Self {
+        Arg::new(join_args(&[&range.start.into_arg(), &range.end.into_arg()]))
+    }
+}
+
+impl From<Client> for Arg {
+    fn from(bound: Client) -> Self {
+        Arg::new(bound.into_arg())
+    }
+}
+
+impl From<Opts> for Arg {
+    fn from(opts: Opts) -> Self {
+        Arg {
+            arg: Bytes::new(),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl<T> From<(T, Opts)> for Arg
+where
+    T: Into<Arg>,
+{
+    fn from((bounds, opts): (T, Opts)) -> Self {
+        Arg {
+            opts: opts.to_vec(),
+            ..bounds.into()
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
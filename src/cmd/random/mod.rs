@@ -0,0 +1,78 @@
+mod arg;
+mod opt;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl r {
+    /// Generate a random number between the given bounds
+    ///
+    /// Given `()`, return a random floating point number in the range
+    /// `[0, 1)`. Given `..x`, return a random integer in the range `[0, x)`,
+    /// and given `x..y`, one in the range `[x, y)`. The bounds can also be
+    /// passed as queries using [r.args]. Random floating point numbers in the
+    /// same ranges can be requested through [Opts].
+    ///
+    /// ## Example
+    ///
+    /// Generate a random number in the range `[0, 1)`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.random(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Generate a random integer in the range `[0, 100)`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.random(..100)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Generate a random number in the range `[-2.24, 1.59)`.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::random::Opts};
+    /// #
+    /// let opts = Opts::builder().float(true).build();
+    /// r.random((-2.24..1.59, opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [sample]
+    ///
+    /// [r.args]: struct.r.html#method.args
+    /// [Opts]: cmd/random/struct.Opts.html
+    /// [sample]: struct.Client.html#method.sample
+    pub fn random<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 151, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::r};
+
+    #[test]
+    fn bounds_and_options_are_serialised() {
+        let opts = Opts::builder().float(true).build();
+        let query = r.random((r.args([1.5, -2.5]), opts));
+        let expected = r#"[151,[[154,[[2,[1.5,-2.5]]]]],{"float":true}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,26 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    float: Option<bool>,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// Return a floating point number instead of an integer (default
+    /// `false`)
+    pub fn float(&mut self, float: bool) -> &mut Self {
+        self.float = Some(float);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
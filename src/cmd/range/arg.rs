@@ -0,0 +1,63 @@
+use {
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+    std::ops::{Range, RangeTo},
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl From<()> for Arg {
+    fn from(_: ()) -> Self {
+        Arg {
+            arg: Bytes::new(),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<T> From<RangeTo<T>> for Arg
+where
+    T: IntoArg,
+{
+    fn from(range: RangeTo<T>) -> Self {
+        Arg {
+            arg: range.end.into_arg(),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<T> From<Range<T>> for Arg
+where
+    T: IntoArg,
+{
+    fn from(range: Range<T>) -> Self {
+        Arg {
+            arg: join_args(&[&range.start.into_arg(), &range.end.into_arg()]),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
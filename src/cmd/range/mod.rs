@@ -0,0 +1,51 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Generate a stream of sequential integers in a specified range
+    ///
+    /// Accepts `()` for an infinite stream starting at `0`, `..end` for a
+    /// stream from `0` up to (but not including) `end`, or `start..end`.
+    ///
+    /// ## Example
+    ///
+    /// Return a four-element range of `[0, 1, 2, 3]`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.range(..4)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Return a range from -5 through 5.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.range(-5..6)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Take the first ten elements of an infinite range.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.range(()).limit(10)
+    /// # ;
+    /// ```
+    pub fn range<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 173, arg.into())
+    }
+}
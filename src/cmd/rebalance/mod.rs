@@ -0,0 +1,31 @@
+use crate::Client;
+
+impl Client {
+    /// Rebalance the shards of a table
+    ///
+    /// When called on a database, all the tables in that database will be
+    /// rebalanced. The result can be deserialised into a [RebalanceStatus].
+    ///
+    /// ## Example
+    ///
+    /// Rebalance a table.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("superheroes").rebalance()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [reconfigure]
+    /// - [status]
+    ///
+    /// [reconfigure]: struct.Client.html#method.reconfigure
+    /// [status]: struct.Client.html#method.status
+    /// [RebalanceStatus]: https://docs.rs/reql-types/*/reql_types/struct.RebalanceStatus.html
+    pub fn rebalance(&self) -> Client {
+        Client::new(&self.0, 179, ())
+    }
+}
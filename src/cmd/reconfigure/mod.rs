@@ -0,0 +1,94 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Reconfigure a table's sharding and replication
+    ///
+    /// When called on a database, all the tables in that database will be
+    /// reconfigured. The new settings are passed through [Opts]. The result
+    /// can be deserialised into a [ReconfigureStatus].
+    ///
+    /// ## Example
+    ///
+    /// Reconfigure a table.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::reconfigure::Opts};
+    /// #
+    /// let opts = Opts::builder().shards(2).replicas(1).build();
+    /// r.table("superheroes").reconfigure(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Reconfigure a table, specifying replicas by server tags.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::reconfigure::Opts};
+    /// #
+    /// let replicas = [("wooster", 1), ("wayne", 1)];
+    /// let opts = Opts::builder()
+    ///     .shards(2)
+    ///     .replicas(&replicas[..])
+    ///     .primary_replica_tag("wooster")
+    ///     .build();
+    /// r.table("superheroes").reconfigure(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Perform an emergency repair on a table.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::reconfigure::{EmergencyRepair, Opts}};
+    /// #
+    /// let opts = Opts::builder()
+    ///     .emergency_repair(EmergencyRepair::UnsafeRollback)
+    ///     .build();
+    /// r.table("superheroes").reconfigure(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [rebalance]
+    /// - [wait]
+    /// - [config]
+    ///
+    /// [Opts]: cmd/reconfigure/struct.Opts.html
+    /// [ReconfigureStatus]: https://docs.rs/reql-types/*/reql_types/struct.ReconfigureStatus.html
+    /// [rebalance]: struct.Client.html#method.rebalance
+    /// [wait]: struct.Client.html#method.wait
+    /// [config]: struct.Client.html#method.config
+    pub fn reconfigure<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 176, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::r};
+
+    #[test]
+    fn replicas_per_tag_are_serialised_as_an_object() {
+        let replicas = [("wooster", 1), ("wayne", 2)];
+        let opts = Opts::builder()
+            .replicas(&replicas[..])
+            .primary_replica_tag("wooster")
+            .dry_run(true)
+            .build();
+        let query = r.table("heroes").reconfigure(opts);
+        let expected = r#"[176,[[15,["heroes"]]],{"replicas":{"wooster":1,"wayne":2},"primary_replica_tag":"wooster","dry_run":true}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,81 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+pub use crate::cmd::table_create::Replicas;
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shards: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replicas: Option<Replicas<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primary_replica_tag: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dry_run: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    emergency_repair: Option<EmergencyRepair>,
+}
+
+/// How to repair a table that has lost a majority of its replicas
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmergencyRepair {
+    /// Make the remaining replicas authoritative, possibly losing writes
+    UnsafeRollback,
+    /// Like `UnsafeRollback`, but also erase shards that have lost all of
+    /// their replicas
+    UnsafeRollbackOrErase,
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// The number of shards, an integer from 1-64
+    pub fn shards(&mut self, shards: u8) -> &mut Self {
+        self.shards = Some(shards);
+        self
+    }
+
+    /// Either the total number of replicas or the number of replicas per
+    /// server tag
+    ///
+    /// When using server tags, [primary_replica_tag] must be set as well.
+    ///
+    /// [primary_replica_tag]: #method.primary_replica_tag
+    pub fn replicas<T>(&mut self, replicas: T) -> &mut Self
+    where
+        T: Into<Replicas<'a>>,
+    {
+        self.replicas = Some(replicas.into());
+        self
+    }
+
+    /// The server tag to use for the primary replicas
+    pub fn primary_replica_tag(&mut self, tag: &'a str) -> &mut Self {
+        self.primary_replica_tag = Some(tag);
+        self
+    }
+
+    /// Return the configuration that would be applied without applying it
+    /// (default `false`)
+    pub fn dry_run(&mut self, dry_run: bool) -> &mut Self {
+        self.dry_run = Some(dry_run);
+        self
+    }
+
+    /// Repair a table that has lost a majority of its replicas
+    ///
+    /// This is used instead of `shards` and `replicas`.
+    pub fn emergency_repair(&mut self, repair: EmergencyRepair) -> &mut Self {
+        self.emergency_repair = Some(repair);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
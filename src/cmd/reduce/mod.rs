@@ -0,0 +1,50 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Produce a single value from a sequence through repeated application of a
+    /// reduction function
+    ///
+    /// The reduction function can be called on:
+    ///
+    /// - two elements of the sequence
+    /// - one element of the sequence and one result of a previous reduction
+    /// - two results of previous reductions
+    ///
+    /// The reduction function can be called on the results of two previous
+    /// reductions because the `reduce` command is distributed and parallelized
+    /// across shards and CPU cores. A common mistake when using the `reduce`
+    /// command is to suppose that the reduction is executed from left to right.
+    ///
+    /// ## Example
+    ///
+    /// Return the number of documents in the table `posts`.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("posts")
+    ///     .map(|_doc: Client| r.expr(1))
+    ///     .reduce(|left: Client, right: Client| left + right)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [fold]
+    /// - [group]
+    /// - [map]
+    ///
+    /// [fold]: struct.Client.html#method.fold
+    /// [group]: struct.Client.html#method.group
+    /// [map]: struct.Client.html#method.map
+    pub fn reduce<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 37, arg.into())
+    }
+}
@@ -0,0 +1,60 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Replace documents in a table
+    ///
+    /// The new document must contain the same primary key as the original
+    /// document. Replacing a document with `null` deletes it. The result of
+    /// the query can be deserialised into a [WriteStatus].
+    ///
+    /// ## Example
+    ///
+    /// Replace all posts with a new document, keeping their primary keys.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("posts").replace(json!({
+    ///     "id": 1,
+    ///     "title": "Lorem ipsum",
+    ///     "content": "Aleas jacta est",
+    ///     "status": "draft",
+    /// }))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Remove the field `status` from all posts.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("posts").replace(|post: Client| post.without("status"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [insert]
+    /// - [update]
+    /// - [delete]
+    ///
+    /// [WriteStatus]: https://docs.rs/reql-types/*/reql_types/struct.WriteStatus.html
+    /// [insert]: struct.Client.html#method.insert
+    /// [update]: struct.Client.html#method.update
+    /// [delete]: struct.Client.html#method.delete
+    pub fn replace<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 55, arg.into())
+    }
+}
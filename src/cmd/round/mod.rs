@@ -0,0 +1,29 @@
+use crate::Client;
+
+impl Client {
+    /// Round a number to the nearest integer
+    ///
+    /// Numbers ending in `.5` are rounded away from zero.
+    ///
+    /// ## Example
+    ///
+    /// Round 12.345 to the nearest integer.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(12.345).round()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [floor]
+    /// - [ceil]
+    ///
+    /// [floor]: struct.Client.html#method.floor
+    /// [ceil]: struct.Client.html#method.ceil
+    pub fn round(&self) -> Client {
+        Client::new(&self.0, 185, ())
+    }
+}
@@ -4,11 +4,11 @@ mod opt;
 use {
     crate::{
         net::{connection::Connection, response::session::Session},
-        Client,
+        Client, Result,
     },
     arg::Arg,
     bytes::Bytes,
-    futures::channel::mpsc::UnboundedReceiver,
+    futures::prelude::*,
     serde::de::DeserializeOwned,
     std::marker::PhantomData,
 };
@@ -32,7 +32,6 @@ pub struct Run<'a, T> {
     pub(crate) query: Bytes,
     pub(crate) opts: Opts<'a>,
     pub(crate) session: Option<Session<'a>>,
-    pub(crate) receiver: Option<UnboundedReceiver<Bytes>>,
     pub(crate) state: State,
     phantom: PhantomData<T>,
 }
@@ -43,7 +42,6 @@ pub(crate) enum State {
     Initialised,
     SessionCreated,
     SessionWritten,
-    SessionRead,
     Done,
 }
 
@@ -60,9 +58,95 @@ impl<'a, T> Run<'a, T> {
             query,
             opts,
             session: None,
-            receiver: None,
             state: State::New,
             phantom: PhantomData,
         }
     }
+
+    // A query of one of the other query types, which is sent as it is
+    pub(crate) fn raw(query: &'static [u8], conn: &'a Connection) -> Self {
+        Run {
+            conn,
+            query: Bytes::from_static(query),
+            opts: Default::default(),
+            session: None,
+            state: State::Initialised,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Stop the query and free the resources held for it on the server
+    ///
+    /// Queries dropped before they have finished are stopped as well, in the
+    /// background. Use this method to wait until the server has actually
+    /// stopped the query.
+    ///
+    /// ## Example
+    ///
+    /// Read the first batch of an endless stream and then stop it.
+    ///
+    /// ```rust,no_run
+    /// # #![feature(async_await, await_macro, futures_api)]
+    /// # use futures::{executor::block_on, prelude::*};
+    /// # use reql::r;
+    /// # fn main() -> reql::Result<()> {
+    /// # block_on(async {
+    /// let conn = await!(r.connect(()))?;
+    /// let mut query = r.range(()).run::<_, u64>(&conn);
+    /// if let Some(resp) = await!(query.next()) {
+    ///     println!("{:?}", resp?);
+    /// }
+    /// await!(query.close())?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn close(mut self) -> impl Future<Output = Result<()>> + 'a {
+        let session = self.session.take();
+        async move {
+            match session {
+                Some(session) => await!(session.close()),
+                None => Ok(()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+    use futures::{executor::block_on, poll, prelude::*};
+
+    #[test]
+    fn unfinished_queries_can_be_closed() -> crate::Result<()> {
+        block_on(
+            async {
+                let conn = await!(r.connect(()))?;
+                let mut query = r.range(()).run::<_, u64>(&conn);
+                let resp = await!(query.next()).unwrap()?;
+                assert_eq!(resp.first(), Some(&0));
+                await!(query.close())?;
+                // the connection is still usable after stopping a query
+                let resp = await!(r.expr(1).run::<_, u64>(&conn))?;
+                assert_eq!(resp.first(), Some(&1));
+                Ok(())
+            },
+        )
+    }
+
+    #[test]
+    fn queries_dropped_before_their_first_response_are_stopped() -> crate::Result<()> {
+        block_on(
+            async {
+                let conn = await!(r.connect(()))?;
+                let mut query = r.range(()).run::<_, u64>(&conn);
+                // sends the query without waiting for its response
+                let _ = poll!(query.next());
+                drop(query);
+                let resp = await!(r.expr(1).run::<_, u64>(&conn))?;
+                assert_eq!(resp.first(), Some(&1));
+                Ok(())
+            },
+        )
+    }
 }
@@ -10,16 +10,21 @@ pub struct Opts<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     time_format: Option<Format>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    binary_format: Option<Format>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     profile: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     durability: Option<Durability>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) noreply: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     group_format: Option<Format>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) db: Option<Db<'a>>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ReadMode {
     Single,
     Majority,
@@ -27,12 +32,14 @@ pub enum ReadMode {
 }
 
 #[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Durability {
     Hard,
     Soft,
 }
 
 #[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Format {
     Native,
     Raw,
@@ -50,6 +57,55 @@ impl<'a> Opts<'a> {
         self.profile = Some(profile);
         self
     }
+
+    /// How up to date the data returned must be (default `single`)
+    pub fn read_mode(&mut self, read_mode: ReadMode) -> &mut Self {
+        self.read_mode = Some(read_mode);
+        self
+    }
+
+    /// Whether times are returned as native times or raw objects (default
+    /// `native`)
+    pub fn time_format(&mut self, format: Format) -> &mut Self {
+        self.time_format = Some(format);
+        self
+    }
+
+    /// Whether binary data is returned as `BINARY` pseudo-types or raw
+    /// strings (default `native`)
+    ///
+    /// Native binary data can be deserialised into `reql_types::Binary`.
+    pub fn binary_format(&mut self, format: Format) -> &mut Self {
+        self.binary_format = Some(format);
+        self
+    }
+
+    /// Whether writes are committed to disk before being acknowledged
+    /// (default `hard`)
+    pub fn durability(&mut self, durability: Durability) -> &mut Self {
+        self.durability = Some(durability);
+        self
+    }
+
+    /// Whether to send the query without waiting for a response (default
+    /// `false`)
+    ///
+    /// The query resolves to an empty response as soon as it has been handed
+    /// over to the connection. Use `Connection::noreply_wait` to wait until
+    /// the server has processed it.
+    pub fn noreply(&mut self, noreply: bool) -> &mut Self {
+        self.noreply = Some(noreply);
+        self
+    }
+
+    /// Whether grouped data is returned as `GROUPED_DATA` or raw objects
+    /// (default `native`)
+    ///
+    /// Native grouped data can be deserialised into `reql_types::Grouped`.
+    pub fn group_format(&mut self, format: Format) -> &mut Self {
+        self.group_format = Some(format);
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
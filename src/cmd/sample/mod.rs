@@ -0,0 +1,29 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Select a given number of elements from a sequence with uniform random
+    /// distribution
+    ///
+    /// Selection is done without replacement.
+    ///
+    /// ## Example
+    ///
+    /// Select 3 random heroes.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").sample(3)
+    /// # ;
+    /// ```
+    pub fn sample<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 81, arg.into())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::Client;
+
+impl Client {
+    /// Return the seconds in a time object as a number between 0 and 59.999
+    /// (double precision)
+    ///
+    /// ## Example
+    ///
+    /// Return the post submitted during the first 30 seconds of every minute.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("posts").filter(|post: Client| post.get_field("date").seconds().lt(30))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [hours]
+    /// - [minutes]
+    ///
+    /// [hours]: struct.Client.html#method.hours
+    /// [minutes]: struct.Client.html#method.minutes
+    pub fn seconds(&self) -> Client {
+        Client::new(&self.0, 135, ())
+    }
+}
@@ -0,0 +1,39 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Remove the elements of one array from another and return them as a set (an
+    /// array with distinct values)
+    ///
+    /// ## Example
+    ///
+    /// Check which pieces of equipment Iron Man has, excluding a fixed list.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").get_field("equipment").set_difference(["newBoots", "arc_reactor"])
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [set_insert]
+    /// - [set_union]
+    /// - [set_intersection]
+    /// - [difference]
+    ///
+    /// [set_insert]: struct.Client.html#method.set_insert
+    /// [set_union]: struct.Client.html#method.set_union
+    /// [set_intersection]: struct.Client.html#method.set_intersection
+    /// [difference]: struct.Client.html#method.difference
+    pub fn set_difference<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 91, arg.into())
+    }
+}
@@ -0,0 +1,39 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Add a value to an array and return it as a set (an array with distinct
+    /// values)
+    ///
+    /// ## Example
+    ///
+    /// Retrieve Iron Man's equipment list with the addition of some new boots.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").get_field("equipment").set_insert("new_boots")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [set_union]
+    /// - [set_intersection]
+    /// - [set_difference]
+    /// - [append]
+    ///
+    /// [set_union]: struct.Client.html#method.set_union
+    /// [set_intersection]: struct.Client.html#method.set_intersection
+    /// [set_difference]: struct.Client.html#method.set_difference
+    /// [append]: struct.Client.html#method.append
+    pub fn set_insert<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 88, arg.into())
+    }
+}
@@ -0,0 +1,37 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Intersect two arrays returning values that occur in both of them as a set
+    /// (an array with distinct values)
+    ///
+    /// ## Example
+    ///
+    /// Check which pieces of equipment Iron Man has from a fixed list.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").get_field("equipment").set_intersection(["newBoots", "arc_reactor"])
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [set_insert]
+    /// - [set_union]
+    /// - [set_difference]
+    ///
+    /// [set_insert]: struct.Client.html#method.set_insert
+    /// [set_union]: struct.Client.html#method.set_union
+    /// [set_difference]: struct.Client.html#method.set_difference
+    pub fn set_intersection<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 89, arg.into())
+    }
+}
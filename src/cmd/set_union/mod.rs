@@ -0,0 +1,39 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Add several values to an array and return it as a set (an array with
+    /// distinct values)
+    ///
+    /// ## Example
+    ///
+    /// Retrieve Iron Man's equipment list with the addition of some new boots and an arc reactor.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").get_field("equipment").set_union(["new_boots", "arc_reactor"])
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [set_insert]
+    /// - [set_intersection]
+    /// - [set_difference]
+    /// - [union]
+    ///
+    /// [set_insert]: struct.Client.html#method.set_insert
+    /// [set_intersection]: struct.Client.html#method.set_intersection
+    /// [set_difference]: struct.Client.html#method.set_difference
+    /// [union]: struct.Client.html#method.union
+    pub fn set_union<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 90, arg.into())
+    }
+}
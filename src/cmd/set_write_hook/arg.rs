@@ -0,0 +1,58 @@
+use {
+    crate::cmd::{Args, Datum, Func, IntoArg, Param},
+    bytes::Bytes,
+    std::marker::PhantomData,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg<K> {
+    arg: Bytes,
+    opts: Vec<u8>,
+    kind: PhantomData<K>,
+}
+
+impl<T> From<T> for Arg<Datum>
+where
+    T: IntoArg,
+{
+    fn from(t: T) -> Self {
+        Arg {
+            arg: t.into_arg(),
+            opts: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<F, A> From<F> for Arg<Func<A>>
+where
+    F: IntoArg<Func<A>>,
+{
+    fn from(f: F) -> Self {
+        Arg {
+            arg: f.into_arg(),
+            opts: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl From<Args> for Arg<Datum> {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> Param for Arg<K> {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
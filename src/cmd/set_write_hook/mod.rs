@@ -0,0 +1,83 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Set the write hook of a table
+    ///
+    /// A write hook is a function that runs on every write to the table,
+    /// letting you change the document being written or reject the write by
+    /// throwing an error. It takes three arguments: the write context (an
+    /// object holding the document's `primary_key` and the `timestamp` of the
+    /// write), the old value of the document and its new value. It returns
+    /// the value to write, or `null` to delete the document. Passing `null`
+    /// instead of a function removes the table's write hook.
+    ///
+    /// Individual writes can skip the hook with the `ignore_write_hook`
+    /// option of [insert], [update], [replace] and [delete].
+    ///
+    /// ## Example
+    ///
+    /// Stamp every written document with the time of the write.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("comments")
+    ///     .set_write_hook(|context: Client, _old: Client, new: Client| {
+    ///         new.merge(context.pluck("timestamp"))
+    ///     })
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Remove the write hook of a table.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("comments").set_write_hook(json!(null))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [get_write_hook]
+    ///
+    /// [insert]: struct.Client.html#method.insert
+    /// [update]: struct.Client.html#method.update
+    /// [replace]: struct.Client.html#method.replace
+    /// [delete]: struct.Client.html#method.delete
+    /// [get_write_hook]: struct.Client.html#method.get_write_hook
+    pub fn set_write_hook<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 189, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{r, Client},
+        serde_json::{json, Value},
+    };
+
+    #[test]
+    fn hook_is_compiled_into_a_three_argument_function() {
+        let query = r
+            .table("comments")
+            .set_write_hook(|_: Client, _: Client, new: Client| new);
+        let term: Value = serde_json::from_slice(&query.0).unwrap();
+        assert_eq!(term[0], 189);
+        let func = &term[1][1];
+        let params = &func[1][0][1];
+        assert_eq!(params.as_array().unwrap().len(), 3);
+        assert_eq!(func[1][1], json!([10, [params[2]]]));
+    }
+}
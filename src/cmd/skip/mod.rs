@@ -0,0 +1,38 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Skip a number of elements from the head of the sequence
+    ///
+    /// ## Example
+    ///
+    /// Here in conjunction with [order_by] we choose to ignore the most successful
+    /// heroes.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").order_by("success_metric").skip(10)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [limit]
+    /// - [slice]
+    /// - [nth]
+    ///
+    /// [order_by]: struct.Client.html#method.order_by
+    /// [limit]: struct.Client.html#method.limit
+    /// [slice]: struct.Client.html#method.slice
+    /// [nth]: struct.Client.html#method.nth
+    pub fn skip<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 70, arg.into())
+    }
+}
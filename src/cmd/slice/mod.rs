@@ -0,0 +1,70 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Return the elements of a sequence within the specified range
+    ///
+    /// Accepts a `(start, end)` tuple, optionally followed by [Opts], or a
+    /// Rust range. Negative indexes count from the end of the sequence. By
+    /// default the start index is included in the slice and the end index is
+    /// not.
+    ///
+    /// ## Example
+    ///
+    /// Return the fourth, fifth and sixth youngest players. (The youngest
+    /// player is at index 0, so those are elements 3-5.)
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("players").order_by("age").slice(3..6)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Return all but the top three players who have a red flag.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("players").filter(json!({"flag": "red"})).slice(3..)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Return holders of tickets `X` through `Y`, assuming tickets are
+    /// numbered sequentially, including ticket `Y`.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::slice::{Bound, Opts}};
+    /// #
+    /// let opts = Opts::builder().right_bound(Bound::Closed).build();
+    /// r.table("users").order_by("ticket").slice((10, 20, opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [skip]
+    /// - [limit]
+    /// - [nth]
+    ///
+    /// [Opts]: cmd/slice/struct.Opts.html
+    /// [skip]: struct.Client.html#method.skip
+    /// [limit]: struct.Client.html#method.limit
+    /// [nth]: struct.Client.html#method.nth
+    pub fn slice<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 30, arg.into())
+    }
+}
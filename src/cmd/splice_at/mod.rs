@@ -0,0 +1,38 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Insert several values in to an array at a given index
+    ///
+    /// Accepts an `(index, array)` tuple. Returns the modified array.
+    ///
+    /// ## Example
+    ///
+    /// Hulk and Thor decide to join the avengers.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(["Iron Man", "Spider-Man"]).splice_at((1, ["Hulk", "Thor"]))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [insert_at]
+    /// - [change_at]
+    /// - [delete_at]
+    ///
+    /// [insert_at]: struct.Client.html#method.insert_at
+    /// [change_at]: struct.Client.html#method.change_at
+    /// [delete_at]: struct.Client.html#method.delete_at
+    pub fn splice_at<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 85, arg.into())
+    }
+}
@@ -0,0 +1,82 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Split a string into substrings
+    ///
+    /// With `()`, splits on whitespace. With a separator, splits on that
+    /// separator; an empty separator splits the string into characters. A
+    /// `(separator, max_splits)` tuple limits the number of splits, and `None`
+    /// can be used as the separator to split on whitespace.
+    ///
+    /// Mimics the behavior of Python's `string.split` in edge cases, except
+    /// for splitting on the empty string, which instead produces an array of
+    /// single-character strings.
+    ///
+    /// ## Example
+    ///
+    /// Split on whitespace.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr("foo  bar bax").split(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Split the entries in a CSV file.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr("12,37,,22,").split(",")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Split on whitespace at most once (i.e. get the first word).
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr("foo  bar bax").split((None::<&str>, 1))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [upcase]
+    /// - [downcase]
+    /// - [match_]
+    ///
+    /// [upcase]: struct.Client.html#method.upcase
+    /// [downcase]: struct.Client.html#method.downcase
+    /// [match_]: struct.Client.html#method.match_
+    pub fn split<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 149, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn separator_and_max_splits_are_serialised() {
+        let query = r.expr("a,b,c").split((",", 1));
+        let expected = r#"[149,["a,b,c",",",1]]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+        let query = r.expr("a b c").split((None::<&str>, 1));
+        let expected = r#"[149,["a b c",null,1]]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,32 @@
+use crate::Client;
+
+impl Client {
+    /// Return the status of a table
+    ///
+    /// The return value is an object providing information about the table's
+    /// shards, replicas and replica readiness states, which can be deserialised
+    /// into a [TableStatus].
+    ///
+    /// ## Example
+    ///
+    /// Get a table's status.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("superheroes").status()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [config]
+    /// - [wait]
+    ///
+    /// [config]: struct.Client.html#method.config
+    /// [wait]: struct.Client.html#method.wait
+    /// [TableStatus]: https://docs.rs/reql-types/*/reql_types/struct.TableStatus.html
+    pub fn status(&self) -> Client {
+        Client::new(&self.0, 175, ())
+    }
+}
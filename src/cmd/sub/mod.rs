@@ -0,0 +1,66 @@
+mod arg;
+
+use {
+    crate::{cmd::IntoArg, Client},
+    std::ops::Sub,
+};
+
+pub use arg::Arg;
+
+impl Client {
+    /// Subtract two numbers
+    ///
+    /// The `-` operator can be used as well. Subtracting a number from a time
+    /// and two times from each other is supported too.
+    ///
+    /// ## Example
+    ///
+    /// It's as easy as 2 - 2 = 0.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(2).sub(2)
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Use the operator instead.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr(2) - 2
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [add]
+    /// - [mul]
+    /// - [div]
+    /// - [mod_]
+    ///
+    /// [add]: struct.Client.html#method.add
+    /// [mul]: struct.Client.html#method.mul
+    /// [div]: struct.Client.html#method.div
+    /// [mod_]: struct.Client.html#method.mod_
+    pub fn sub<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 25, arg.into())
+    }
+}
+
+impl<T> Sub<T> for Client
+where
+    T: IntoArg,
+{
+    type Output = Client;
+
+    fn sub(self, rhs: T) -> Client {
+        Client::sub(&self, rhs)
+    }
+}
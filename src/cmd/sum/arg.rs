@@ -0,0 +1,78 @@
+use {
+    crate::{
+        cmd::{Args, Datum, Func, IntoArg, NoArg, Param},
+        Client,
+    },
+    bytes::Bytes,
+    std::marker::PhantomData,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg<K> {
+    arg: Bytes,
+    opts: Vec<u8>,
+    kind: PhantomData<K>,
+}
+
+impl<K> Arg<K> {
+    fn new(arg: Bytes) -> Self {
+        Arg {
+            arg,
+            opts: Vec::new(),
+            kind: PhantomData,
+        }
+    }
+}
+
+impl From<()> for Arg<NoArg> {
+    fn from(_: ()) -> Self {
+        Arg::new(Bytes::new())
+    }
+}
+
+impl<'a> From<&'a str> for Arg<Datum> {
+    fn from(field: &'a str) -> Self {
+        Arg::new(field.into_arg())
+    }
+}
+
+impl From<String> for Arg<Datum> {
+    fn from(field: String) -> Self {
+        Arg::new(field.into_arg())
+    }
+}
+
+impl From<Client> for Arg<Datum> {
+    fn from(field: Client) -> Self {
+        Arg::new(field.into_arg())
+    }
+}
+
+impl<F, A> From<F> for Arg<Func<A>>
+where
+    F: IntoArg<Func<A>>,
+{
+    fn from(f: F) -> Self {
+        Arg::new(f.into_arg())
+    }
+}
+
+impl From<Args> for Arg<Datum> {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+            kind: PhantomData,
+        }
+    }
+}
+
+impl<K> Param for Arg<K> {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,54 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Sum all the elements of a sequence
+    ///
+    /// If called with a field name, sum all the values of that field in the
+    /// sequence, skipping elements of the sequence that lack that field. If called
+    /// with a function, call that function on every element of the sequence and sum
+    /// the results, skipping elements of the sequence where that function returns
+    /// `null` or a non-existence error. Returns `0` when called on an empty
+    /// sequence.
+    ///
+    /// ## Example
+    ///
+    /// What's 3 + 5 + 7?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr([3, 5, 7]).sum(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// How many points have been scored across all games?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("games").sum("points")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [count]
+    /// - [avg]
+    /// - [group]
+    ///
+    /// [count]: struct.Client.html#method.count
+    /// [avg]: struct.Client.html#method.avg
+    /// [group]: struct.Client.html#method.group
+    pub fn sum<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 145, arg.into())
+    }
+}
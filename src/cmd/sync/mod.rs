@@ -0,0 +1,32 @@
+use crate::Client;
+
+impl Client {
+    /// Ensure that writes on a given table are written to permanent storage
+    ///
+    /// Queries that specify soft durability do not wait for writes to be committed
+    /// to disk; a call to `sync` on a table will not return until all previous
+    /// writes to the table are completed, guaranteeing the data's persistence. The
+    /// result can be deserialised into a [SyncStatus].
+    ///
+    /// ## Example
+    ///
+    /// After having updated multiple heroes with soft durability, we now want to
+    /// wait until these changes are persisted.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").sync()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [wait]
+    ///
+    /// [wait]: struct.Client.html#method.wait
+    /// [SyncStatus]: https://docs.rs/reql-types/*/reql_types/struct.SyncStatus.html
+    pub fn sync(&self) -> Client {
+        Client::new(&self.0, 138, ())
+    }
+}
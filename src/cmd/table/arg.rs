@@ -1,5 +1,9 @@
 use {
-    crate::{cmd::Param, ser::to_vec},
+    super::Opts,
+    crate::{
+        cmd::{Args, Param},
+        ser::to_vec,
+    },
     bytes::Bytes,
 };
 
@@ -27,12 +31,39 @@ impl From<String> for Arg {
     }
 }
 
+impl<'a> From<(&'a str, Opts)> for Arg {
+    fn from((t, opts): (&'a str, Opts)) -> Self {
+        Arg {
+            arg: Bytes::from(to_vec(t)),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<(String, Opts)> for Arg {
+    fn from((t, opts): (String, Opts)) -> Self {
+        Arg {
+            arg: Bytes::from(to_vec(&t)),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
 impl Param for Arg {
-    fn arg(&self) -> &Bytes {
+    fn arg(&self) -> &[u8] {
         &self.arg
     }
 
-    fn opts(&self) -> &Vec<u8> {
+    fn opts(&self) -> &[u8] {
         &self.opts
     }
 }
@@ -7,12 +7,53 @@ use {
 };
 
 pub use arg::Arg;
+pub use opt::*;
 
 fn table(prev: &Bytes, arg: Arg) -> Client {
     Client::new(prev, 15, arg)
 }
 
 impl r {
+    /// Return all documents in a table
+    ///
+    /// Other commands may be chained after `table` to return a subset of
+    /// documents (such as [get] and [filter]) or perform further processing.
+    /// The table's read mode and how system tables identify other objects
+    /// can be changed through [Opts].
+    ///
+    /// ## Example
+    ///
+    /// Return all documents in the table `marvel` of the default database.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Allow potentially out-of-date data in exchange for faster reads.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::table::{Opts, ReadMode}};
+    /// #
+    /// let opts = Opts::builder().read_mode(ReadMode::Outdated).build();
+    /// r.table(("marvel", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [db]
+    /// - [get]
+    /// - [filter]
+    ///
+    /// [Opts]: cmd/table/struct.Opts.html
+    /// [db]: struct.r.html#method.db
+    /// [get]: struct.Client.html#method.get
+    /// [filter]: struct.Client.html#method.filter
     pub fn table<A>(&self, arg: A) -> Client
     where
         A: Into<Arg>,
@@ -22,6 +63,22 @@ impl r {
 }
 
 impl Client {
+    /// Return all documents in a table of this database
+    ///
+    /// See [r.table] for details.
+    ///
+    /// ## Example
+    ///
+    /// Return all documents in the table `marvel` of the `heroes` database.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.db("heroes").table("marvel")
+    /// # ;
+    /// ```
+    ///
+    /// [r.table]: struct.r.html#method.table
     pub fn table<A>(&self, arg: A) -> Client
     where
         A: Into<Arg>,
@@ -29,3 +86,19 @@ impl Client {
         table(&self.0, arg.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::r};
+
+    #[test]
+    fn table_options_are_serialised_after_the_name() {
+        let opts = Opts::builder()
+            .read_mode(ReadMode::Outdated)
+            .identifier_format(IdentifierFormat::Uuid)
+            .build();
+        let query = r.db("heroes").table(("marvel", opts));
+        let expected = r#"[15,[[14,["heroes"]],"marvel"],{"read_mode":"outdated","identifier_format":"uuid"}]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
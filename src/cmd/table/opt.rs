@@ -1,8 +1,12 @@
-#![allow(dead_code)]
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
 
-use {crate::cmd::make_builder, serde::Serialize};
+pub use crate::cmd::run::ReadMode;
 
 #[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
 pub struct Opts {
     #[serde(skip_serializing_if = "Option::is_none")]
     read_mode: Option<ReadMode>,
@@ -10,14 +14,7 @@ pub struct Opts {
     identifier_format: Option<IdentifierFormat>,
 }
 
-#[derive(Debug, Clone, Copy, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ReadMode {
-    Single,
-    Majority,
-    Outdated,
-}
-
+/// How tables, databases and servers are identified in system tables
 #[derive(Debug, Clone, Copy, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum IdentifierFormat {
@@ -28,13 +25,20 @@ pub enum IdentifierFormat {
 impl Opts {
     make_builder!();
 
+    /// How up to date the data returned must be (default `single`)
     pub fn read_mode(&mut self, mode: ReadMode) -> &mut Self {
         self.read_mode = Some(mode);
         self
     }
 
+    /// Whether references to other objects in system tables are names or
+    /// UUIDs (default `name`)
     pub fn identifier_format(&mut self, fmt: IdentifierFormat) -> &mut Self {
         self.identifier_format = Some(fmt);
         self
     }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
 }
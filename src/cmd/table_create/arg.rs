@@ -0,0 +1,60 @@
+use {
+    super::Opts,
+    crate::{
+        cmd::{Args, Param},
+        ser::to_vec,
+    },
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<'a> From<&'a str> for Arg {
+    fn from(t: &'a str) -> Self {
+        Arg {
+            arg: Bytes::from(to_vec(t)),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<String> for Arg {
+    fn from(t: String) -> Self {
+        Arg {
+            arg: Bytes::from(to_vec(&t)),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<'a, 'b> From<(&'a str, Opts<'b>)> for Arg {
+    fn from((t, opts): (&'a str, Opts<'b>)) -> Self {
+        Arg {
+            arg: Bytes::from(to_vec(t)),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,111 @@
+mod arg;
+mod opt;
+
+use {
+    crate::{r, Client},
+    bytes::Bytes,
+};
+
+pub use arg::Arg;
+pub use opt::*;
+
+fn table_create(prev: &Bytes, arg: Arg) -> Client {
+    Client::new(prev, 60, arg)
+}
+
+impl r {
+    /// Create a table in the default database
+    ///
+    /// A RethinkDB table is a collection of JSON documents. The result of the
+    /// query can be deserialised into a [TableCreateStatus]. If a table with
+    /// the same name already exists, the command throws a runtime error.
+    ///
+    /// ## Example
+    ///
+    /// Create a table named `dc_universe` with the primary key `name`.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::table_create::Opts};
+    /// #
+    /// let opts = Opts::builder().primary_key("name").build();
+    /// r.table_create(("dc_universe", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Create a table with 2 shards and 3 replicas per shard, split between
+    /// two server tags.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::table_create::Opts};
+    /// #
+    /// let replicas = [("us_east", 2), ("us_west", 1)];
+    /// let opts = Opts::builder()
+    ///     .shards(2)
+    ///     .replicas(&replicas[..])
+    ///     .primary_replica_tag("us_east")
+    ///     .build();
+    /// r.db("test").table_create(("dc_universe", opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [table_drop]
+    /// - [table_list]
+    /// - [index_create]
+    ///
+    /// [TableCreateStatus]: https://docs.rs/reql-types/*/reql_types/struct.TableCreateStatus.html
+    /// [table_drop]: struct.r.html#method.table_drop
+    /// [table_list]: struct.r.html#method.table_list
+    /// [index_create]: struct.Client.html#method.index_create
+    pub fn table_create<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        table_create(&Bytes::new(), arg.into())
+    }
+}
+
+impl Client {
+    /// Create a table in this database
+    ///
+    /// See [r.table_create] for details.
+    ///
+    /// [r.table_create]: struct.r.html#method.table_create
+    pub fn table_create<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        table_create(&self.0, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+    use futures::executor::block_on;
+    use reql_types::{IndexCreateStatus, IndexStatus, TableCreateStatus, TableDropStatus};
+
+    #[test]
+    fn table_and_index_lifecycle_works() -> crate::Result<()> {
+        block_on(
+            async {
+                let conn = await!(r.connect(()))?;
+                let table = "reql_table_create_test";
+                let resp = await!(r.table_create(table).run::<_, TableCreateStatus>(&conn))?;
+                assert_eq!(resp.first().unwrap().tables_created, 1);
+                let query = r.table(table).index_create("name");
+                let resp = await!(query.run::<_, IndexCreateStatus>(&conn))?;
+                assert_eq!(resp.first().unwrap().created, 1);
+                let query = r.table(table).index_wait("name");
+                let resp = await!(query.run::<_, Vec<IndexStatus>>(&conn))?;
+                assert!(resp.first().unwrap().iter().all(|index| index.ready));
+                let resp = await!(r.table_drop(table).run::<_, TableDropStatus>(&conn))?;
+                assert_eq!(resp.first().unwrap().tables_dropped, 1);
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,106 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::{ser::SerializeMap, Serialize, Serializer},
+};
+
+pub use crate::cmd::insert::Durability;
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primary_key: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    durability: Option<Durability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shards: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replicas: Option<Replicas<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primary_replica_tag: Option<&'a str>,
+}
+
+/// The number of replicas per shard
+#[derive(Debug, Clone, Copy)]
+pub enum Replicas<'a> {
+    /// The total number of replicas
+    Total(usize),
+    /// The number of replicas for each server tag
+    Tags(&'a [(&'a str, usize)]),
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// The name of the primary key (default `id`)
+    pub fn primary_key(&mut self, key: &'a str) -> &mut Self {
+        self.primary_key = Some(key);
+        self
+    }
+
+    /// Override the durability of the table (default `hard`)
+    pub fn durability(&mut self, durability: Durability) -> &mut Self {
+        self.durability = Some(durability);
+        self
+    }
+
+    /// The number of shards, an integer from 1-64 (default `1`)
+    pub fn shards(&mut self, shards: u8) -> &mut Self {
+        self.shards = Some(shards);
+        self
+    }
+
+    /// Either the total number of replicas or the number of replicas per
+    /// server tag (default `1`)
+    ///
+    /// When using server tags, [primary_replica_tag] must be set as well.
+    ///
+    /// [primary_replica_tag]: #method.primary_replica_tag
+    pub fn replicas<T>(&mut self, replicas: T) -> &mut Self
+    where
+        T: Into<Replicas<'a>>,
+    {
+        self.replicas = Some(replicas.into());
+        self
+    }
+
+    /// The server tag to use for the primary replicas
+    pub fn primary_replica_tag(&mut self, tag: &'a str) -> &mut Self {
+        self.primary_replica_tag = Some(tag);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
+
+impl<'a> From<usize> for Replicas<'a> {
+    fn from(total: usize) -> Self {
+        Replicas::Total(total)
+    }
+}
+
+impl<'a> From<&'a [(&'a str, usize)]> for Replicas<'a> {
+    fn from(tags: &'a [(&'a str, usize)]) -> Self {
+        Replicas::Tags(tags)
+    }
+}
+
+impl<'a> Serialize for Replicas<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Replicas::Total(total) => total.serialize(serializer),
+            Replicas::Tags(tags) => {
+                let mut map = serializer.serialize_map(Some(tags.len()))?;
+                for (tag, replicas) in tags.iter() {
+                    map.serialize_entry(tag, replicas)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
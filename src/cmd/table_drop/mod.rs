@@ -0,0 +1,60 @@
+mod arg;
+
+use {
+    crate::{r, Client},
+    bytes::Bytes,
+};
+
+pub use arg::Arg;
+
+fn table_drop(prev: &Bytes, arg: Arg) -> Client {
+    Client::new(prev, 61, arg)
+}
+
+impl r {
+    /// Drop a table from the default database
+    ///
+    /// The table and all its data will be deleted. The result of the query
+    /// can be deserialised into a [TableDropStatus]. If the given table does
+    /// not exist in the database, the command throws a runtime error.
+    ///
+    /// ## Example
+    ///
+    /// Drop a table named `dc_universe`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.db("test").table_drop("dc_universe")
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [table_create]
+    /// - [table_list]
+    ///
+    /// [TableDropStatus]: https://docs.rs/reql-types/*/reql_types/struct.TableDropStatus.html
+    /// [table_create]: struct.r.html#method.table_create
+    /// [table_list]: struct.r.html#method.table_list
+    pub fn table_drop<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        table_drop(&Bytes::new(), arg.into())
+    }
+}
+
+impl Client {
+    /// Drop a table from this database
+    ///
+    /// See [r.table_drop] for details.
+    ///
+    /// [r.table_drop]: struct.r.html#method.table_drop
+    pub fn table_drop<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        table_drop(&self.0, arg.into())
+    }
+}
@@ -0,0 +1,48 @@
+use {
+    crate::{r, Client},
+    bytes::Bytes,
+};
+
+fn table_list(prev: &Bytes) -> Client {
+    Client::new(prev, 62, ())
+}
+
+impl r {
+    /// List all table names in the default database
+    ///
+    /// The result is a list of strings, so it can be deserialised into a
+    /// `Vec<String>`.
+    ///
+    /// ## Example
+    ///
+    /// List all tables of the `test` database.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.db("test").table_list()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [table_create]
+    /// - [table_drop]
+    ///
+    /// [table_create]: struct.r.html#method.table_create
+    /// [table_drop]: struct.r.html#method.table_drop
+    pub fn table_list(&self) -> Client {
+        table_list(&Bytes::new())
+    }
+}
+
+impl Client {
+    /// List all table names in this database
+    ///
+    /// See [r.table_list] for details.
+    ///
+    /// [r.table_list]: struct.r.html#method.table_list
+    pub fn table_list(&self) -> Client {
+        table_list(&self.0)
+    }
+}
@@ -0,0 +1,77 @@
+use {
+    crate::cmd::{join_args, Args, IntoArg, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl<Y, M, D, Z> From<(Y, M, D, Z)> for Arg
+where
+    Y: IntoArg,
+    M: IntoArg,
+    D: IntoArg,
+    Z: IntoArg,
+{
+    fn from((year, month, day, timezone): (Y, M, D, Z)) -> Self {
+        let args = [
+            &year.into_arg()[..],
+            &month.into_arg(),
+            &day.into_arg(),
+            &timezone.into_arg(),
+        ];
+        Arg {
+            arg: join_args(&args),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl<Y, M, D, H, Mi, S, Z> From<(Y, M, D, H, Mi, S, Z)> for Arg
+where
+    Y: IntoArg,
+    M: IntoArg,
+    D: IntoArg,
+    H: IntoArg,
+    Mi: IntoArg,
+    S: IntoArg,
+    Z: IntoArg,
+{
+    fn from((year, month, day, hour, minute, second, timezone): (Y, M, D, H, Mi, S, Z)) -> Self {
+        let args = [
+            &year.into_arg()[..],
+            &month.into_arg(),
+            &day.into_arg(),
+            &hour.into_arg(),
+            &minute.into_arg(),
+            &second.into_arg(),
+            &timezone.into_arg(),
+        ];
+        Arg {
+            arg: join_args(&args),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
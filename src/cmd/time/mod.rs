@@ -0,0 +1,64 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Create a time object for a specific time
+    ///
+    /// Accepts either a `(year, month, day, timezone)` or a `(year, month,
+    /// day, hour, minute, second, timezone)` tuple. The timezone has to be of
+    /// the ISO 8601 format, such as `"+05:30"` or `"Z"`.
+    ///
+    /// ## Example
+    ///
+    /// Create a time object for November 3rd, 1986 in UTC.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.time((1986, 11, 3, "Z"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Create a time object for 13:45:30.5 on that day in Pacific Standard
+    /// Time.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.time((1986, 11, 3, 13, 45, 30.5, "-08:00"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.now]
+    /// - [r.epoch_time]
+    /// - [r.iso8601]
+    ///
+    /// [r.now]: struct.r.html#method.now
+    /// [r.epoch_time]: struct.r.html#method.epoch_time
+    /// [r.iso8601]: struct.r.html#method.iso8601
+    pub fn time<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 136, arg.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::r;
+
+    #[test]
+    fn date_and_time_are_serialised_as_arguments() {
+        let query = r.time((1986, 11, 3, 13, 45, 30.5, "-08:00"));
+        let expected = r#"[136,[1986,11,3,13,45,30.5,"-08:00"]]"#;
+        assert_eq!(&query.0[..], expected.as_bytes());
+    }
+}
@@ -0,0 +1,26 @@
+use crate::Client;
+
+impl Client {
+    /// Return the number of seconds elapsed since the beginning of the day
+    /// stored in the time object
+    ///
+    /// ## Example
+    ///
+    /// Retrieve posts that were submitted before noon.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("posts").filter(|post: Client| post.get_field("date").time_of_day().le(12 * 60 * 60))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [date]
+    ///
+    /// [date]: struct.Client.html#method.date
+    pub fn time_of_day(&self) -> Client {
+        Client::new(&self.0, 126, ())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::Client;
+
+impl Client {
+    /// Return the timezone of the time object
+    ///
+    /// ## Example
+    ///
+    /// Return all the users in the "-07:00" timezone.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("users").filter(|user: Client| user.get_field("subscription_date").timezone().eq("-07:00"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [in_timezone]
+    ///
+    /// [in_timezone]: struct.Client.html#method.in_timezone
+    pub fn timezone(&self) -> Client {
+        Client::new(&self.0, 127, ())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::Client;
+
+impl Client {
+    /// Convert a time object to its epoch time
+    ///
+    /// ## Example
+    ///
+    /// Return the current time in seconds since the Unix Epoch with millisecond
+    /// precision.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.now().to_epoch_time()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.epoch_time]
+    /// - [to_iso8601]
+    ///
+    /// [r.epoch_time]: struct.r.html#method.epoch_time
+    /// [to_iso8601]: struct.Client.html#method.to_iso8601
+    pub fn to_epoch_time(&self) -> Client {
+        Client::new(&self.0, 102, ())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::Client;
+
+impl Client {
+    /// Convert a ReQL geometry object to a [GeoJSON] object
+    ///
+    /// ## Example
+    ///
+    /// Convert a ReQL geometry object to a GeoJSON object.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("geo").get("sfo").get_field("location").to_geojson()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.geojson]
+    ///
+    /// [r.geojson]: struct.r.html#method.geojson
+    /// [GeoJSON]: http://geojson.org
+    pub fn to_geojson(&self) -> Client {
+        Client::new(&self.0, 158, ())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Convert a time object to a string in ISO 8601 format
+    ///
+    /// ## Example
+    ///
+    /// Return the current ISO 8601 time.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.now().to_iso8601()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.iso8601]
+    /// - [to_epoch_time]
+    ///
+    /// [r.iso8601]: struct.r.html#method.iso8601
+    /// [to_epoch_time]: struct.Client.html#method.to_epoch_time
+    pub fn to_iso8601(&self) -> Client {
+        Client::new(&self.0, 100, ())
+    }
+}
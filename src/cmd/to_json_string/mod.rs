@@ -0,0 +1,25 @@
+use crate::Client;
+
+impl Client {
+    /// Convert a ReQL value or object to a JSON string
+    ///
+    /// ## Example
+    ///
+    /// Get a ReQL document as a JSON string.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("hero").get(1).to_json_string()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [r.json]
+    ///
+    /// [r.json]: struct.r.html#method.json
+    pub fn to_json_string(&self) -> Client {
+        Client::new(&self.0, 172, ())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Client;
+
+impl Client {
+    /// Get the type of a ReQL query's return value
+    ///
+    /// The type will be returned as a string, such as `NUMBER`, `STRING`, `ARRAY`,
+    /// `OBJECT` or `TABLE`.
+    ///
+    /// ## Example
+    ///
+    /// Get the type of a string.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr("foo").type_of()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [coerce_to]
+    /// - [info]
+    ///
+    /// [coerce_to]: struct.Client.html#method.coerce_to
+    /// [info]: struct.Client.html#method.info
+    pub fn type_of(&self) -> Client {
+        Client::new(&self.0, 52, ())
+    }
+}
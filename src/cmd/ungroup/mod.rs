@@ -0,0 +1,39 @@
+use crate::Client;
+
+impl Client {
+    /// Take a grouped stream or grouped data and turn it into an array of objects
+    /// representing the groups
+    ///
+    /// Any commands chained after `ungroup` will operate on this array, rather than
+    /// operating on each group individually. This is useful if you want to e.g.
+    /// order the groups by the value of their reduction.
+    ///
+    /// The format of the array returned by `ungroup` is the same as the default
+    /// native format of grouped data in the JavaScript driver and Data Explorer,
+    /// objects with a `group` and a `reduction` field.
+    ///
+    /// ## Example
+    ///
+    /// What is the maximum number of points scored by each player, with the highest
+    /// scorers first?
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("games")
+    ///     .group("player")
+    ///     .max("points")
+    ///     .ungroup()
+    ///     .order_by(r.desc("reduction"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [group]
+    ///
+    /// [group]: struct.Client.html#method.group
+    pub fn ungroup(&self) -> Client {
+        Client::new(&self.0, 150, ())
+    }
+}
@@ -0,0 +1,58 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Merge two or more sequences
+    ///
+    /// To merge more than two sequences, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// Construct a stream of all heroes.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").union(r.table("dc"))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Combine four arrays into one.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr([1, 2]).union(r.args([[3, 4], [5, 6], [7, 8]]))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Merge two tables that are already ordered by `date`, keeping the
+    /// result ordered.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::union::Opts};
+    /// #
+    /// let opts = Opts::builder().interleave("date").build();
+    /// r.table("posts")
+    ///     .order_by("date")
+    ///     .union((r.table("comments").order_by("date"), opts))
+    /// # ;
+    /// ```
+    ///
+    /// [r.args]: struct.r.html#method.args
+    pub fn union<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 44, arg.into())
+    }
+}
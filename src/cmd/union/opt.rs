@@ -0,0 +1,62 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::{Serialize, Serializer},
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interleave: Option<Interleave<'a>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Interleave<'a> {
+    Bool(bool),
+    Field(&'a str),
+}
+
+impl<'a> Opts<'a> {
+    make_builder!();
+
+    /// How to interleave the sequences
+    ///
+    /// `true` (the default) mixes them in whatever order is fastest, `false`
+    /// concatenates them in order and passing a field name merges sequences
+    /// that are already ordered by that field.
+    pub fn interleave<T>(&mut self, interleave: T) -> &mut Self
+    where
+        T: Into<Interleave<'a>>,
+    {
+        self.interleave = Some(interleave.into());
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
+
+impl<'a> From<bool> for Interleave<'a> {
+    fn from(interleave: bool) -> Self {
+        Interleave::Bool(interleave)
+    }
+}
+
+impl<'a> From<&'a str> for Interleave<'a> {
+    fn from(field: &'a str) -> Self {
+        Interleave::Field(field)
+    }
+}
+
+impl<'a> Serialize for Interleave<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Interleave::Bool(value) => value.serialize(serializer),
+            Interleave::Field(field) => field.serialize(serializer),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Uppercase a string
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.expr("Sentence about LaTeX.").upcase()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [downcase]
+    /// - [match_]
+    /// - [split]
+    ///
+    /// [downcase]: struct.Client.html#method.downcase
+    /// [match_]: struct.Client.html#method.match_
+    /// [split]: struct.Client.html#method.split
+    pub fn upcase(&self) -> Client {
+        Client::new(&self.0, 141, ())
+    }
+}
@@ -0,0 +1,58 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Update JSON documents in a table
+    ///
+    /// Accepts a JSON document or a ReQL expression, and can be used on a
+    /// single document or a selection. The result of the query can be
+    /// deserialised into a [WriteStatus].
+    ///
+    /// ## Example
+    ///
+    /// Update the status of all posts to `published`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("posts").update(json!({"status": "published"}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Update all posts and return the changes.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use reql::cmd::update::Opts;
+    /// # use serde_json::json;
+    /// #
+    /// let opts = Opts::builder().return_changes(true).build();
+    /// r.table("posts").update((json!({"status": "published"}), opts))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [insert]
+    /// - [replace]
+    /// - [delete]
+    ///
+    /// [WriteStatus]: https://docs.rs/reql-types/*/reql_types/struct.WriteStatus.html
+    /// [insert]: struct.Client.html#method.insert
+    /// [replace]: struct.Client.html#method.replace
+    /// [delete]: struct.Client.html#method.delete
+    pub fn update<A, K>(&self, arg: A) -> Client
+    where
+        A: Into<Arg<K>>,
+    {
+        Client::new(&self.0, 53, arg.into())
+    }
+}
@@ -0,0 +1,57 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+pub use crate::cmd::insert::{Durability, ReturnChanges};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    durability: Option<Durability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    return_changes: Option<ReturnChanges>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    non_atomic: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_write_hook: Option<bool>,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// Override the durability of the table or query (default `hard`)
+    pub fn durability(&mut self, durability: Durability) -> &mut Self {
+        self.durability = Some(durability);
+        self
+    }
+
+    /// Return a `changes` array of `old_val`/`new_val` objects (default `false`)
+    pub fn return_changes<T>(&mut self, return_changes: T) -> &mut Self
+    where
+        T: Into<ReturnChanges>,
+    {
+        self.return_changes = Some(return_changes.into());
+        self
+    }
+
+    /// Allow the write to be performed non-atomically (default `false`)
+    ///
+    /// This is required for writes that can't be proven to be deterministic,
+    /// such as those using `r.js` or subqueries.
+    pub fn non_atomic(&mut self, non_atomic: bool) -> &mut Self {
+        self.non_atomic = Some(non_atomic);
+        self
+    }
+
+    /// Skip the write hook of the table, if any (default `false`)
+    pub fn ignore_write_hook(&mut self, ignore: bool) -> &mut Self {
+        self.ignore_write_hook = Some(ignore);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
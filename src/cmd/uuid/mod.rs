@@ -0,0 +1,41 @@
+mod arg;
+
+use crate::{r, Client};
+
+pub use arg::Arg;
+
+impl r {
+    /// Return a UUID (universally unique identifier), a string that can be used as
+    /// a unique ID
+    ///
+    /// If a string is passed to `uuid` as an argument, the UUID will be
+    /// deterministic, derived from the string's SHA-1 hash.
+    ///
+    /// ## Example
+    ///
+    /// Generate a UUID.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.uuid(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Generate a UUID based on a string.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.uuid("slava@example.com")
+    /// # ;
+    /// ```
+    pub fn uuid<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&[], 169, arg.into())
+    }
+}
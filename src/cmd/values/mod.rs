@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Return an array containing all of an object's values
+    ///
+    /// `values` guarantees the values will come out in the same order as [keys].
+    ///
+    /// ## Example
+    ///
+    /// Get all of the values from a table row.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").get(1).values()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [keys]
+    ///
+    /// [keys]: struct.Client.html#method.keys
+    pub fn values(&self) -> Client {
+        Client::new(&self.0, 186, ())
+    }
+}
@@ -0,0 +1,48 @@
+use {
+    super::Opts,
+    crate::cmd::{Args, Param},
+    bytes::Bytes,
+};
+
+#[derive(Debug, Clone)]
+pub struct Arg {
+    arg: Bytes,
+    opts: Vec<u8>,
+}
+
+impl From<()> for Arg {
+    fn from(_: ()) -> Self {
+        Arg {
+            arg: Bytes::new(),
+            opts: Vec::new(),
+        }
+    }
+}
+
+impl From<Opts> for Arg {
+    fn from(opts: Opts) -> Self {
+        Arg {
+            arg: Bytes::new(),
+            opts: opts.to_vec(),
+        }
+    }
+}
+
+impl From<Args> for Arg {
+    fn from(args: Args) -> Self {
+        Arg {
+            arg: args.arg.freeze(),
+            opts: args.opts,
+        }
+    }
+}
+
+impl Param for Arg {
+    fn arg(&self) -> &[u8] {
+        &self.arg
+    }
+
+    fn opts(&self) -> &[u8] {
+        &self.opts
+    }
+}
@@ -0,0 +1,59 @@
+mod arg;
+mod opt;
+
+use crate::Client;
+
+pub use arg::Arg;
+pub use opt::*;
+
+impl Client {
+    /// Wait for a table or all the tables in a database to be ready
+    ///
+    /// A table may be temporarily unavailable after creation, rebalancing or
+    /// reconfiguring. The `wait` command blocks until the given table (or
+    /// database) is fully up to date. The result can be deserialised into a
+    /// [WaitStatus].
+    ///
+    /// ## Example
+    ///
+    /// Wait on a table to be ready.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("superheroes").wait(())
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Wait at most 10 seconds for a table to accept writes.
+    ///
+    /// ```rust
+    /// # use reql::{r, cmd::wait::{Opts, WaitFor}};
+    /// #
+    /// let opts = Opts::builder()
+    ///     .wait_for(WaitFor::ReadyForWrites)
+    ///     .timeout(10.0)
+    ///     .build();
+    /// r.table("superheroes").wait(opts)
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [status]
+    /// - [rebalance]
+    /// - [reconfigure]
+    ///
+    /// [WaitStatus]: https://docs.rs/reql-types/*/reql_types/struct.WaitStatus.html
+    /// [status]: struct.Client.html#method.status
+    /// [rebalance]: struct.Client.html#method.rebalance
+    /// [reconfigure]: struct.Client.html#method.reconfigure
+    pub fn wait<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 177, arg.into())
+    }
+}
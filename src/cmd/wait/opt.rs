@@ -0,0 +1,44 @@
+use {
+    crate::{cmd::make_builder, ser::opts_to_vec},
+    serde::Serialize,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Default)]
+#[serde(remote = "Self")]
+pub struct Opts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait_for: Option<WaitFor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timeout: Option<f64>,
+}
+
+/// The readiness state to wait for
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WaitFor {
+    ReadyForOutdatedReads,
+    ReadyForReads,
+    ReadyForWrites,
+    AllReplicasReady,
+}
+
+impl Opts {
+    make_builder!();
+
+    /// The readiness state to wait for (default `all_replicas_ready`)
+    pub fn wait_for(&mut self, wait_for: WaitFor) -> &mut Self {
+        self.wait_for = Some(wait_for);
+        self
+    }
+
+    /// The number of seconds to wait before giving up with an error (default
+    /// no timeout)
+    pub fn timeout(&mut self, timeout: f64) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub(crate) fn to_vec(self) -> Vec<u8> {
+        opts_to_vec(|ser| Self::serialize(&self, ser))
+    }
+}
@@ -0,0 +1,39 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// Pluck the given fields out of the documents that have all of them
+    ///
+    /// Documents missing any of the fields are skipped. To select more than one
+    /// field, pass them using [r.args].
+    ///
+    /// ## Example
+    ///
+    /// Get a list of users and their posts, excluding any users who have not made
+    /// any posts.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("users").with_fields(r.args(["id", "user", "posts"]))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [has_fields]
+    /// - [pluck]
+    ///
+    /// [r.args]: struct.r.html#method.args
+    /// [has_fields]: struct.Client.html#method.has_fields
+    /// [pluck]: struct.Client.html#method.pluck
+    pub fn with_fields<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 96, arg.into())
+    }
+}
@@ -0,0 +1,51 @@
+mod arg;
+
+use crate::Client;
+
+pub use arg::Arg;
+
+impl Client {
+    /// The opposite of [pluck]; takes an object or a sequence of objects, and
+    /// returns them with the given fields removed
+    ///
+    /// To remove more than one field, pass them using [r.args]. Nested fields can
+    /// be removed using an object, which works like `without` on the nested value.
+    ///
+    /// ## Example
+    ///
+    /// Since we don't need it for this computation we'll save bandwidth and leave out the list of IronMan's romantic conquests.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").get("IronMan").without("personal_victories_list")
+    /// # ;
+    /// ```
+    ///
+    /// ## Example
+    ///
+    /// Leave out the damage and mana abilities of the heroes.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// # use serde_json::json;
+    /// #
+    /// r.table("marvel").without(json!({"abilities": {"damage": true, "mana": true}}))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [pluck]
+    /// - [merge]
+    ///
+    /// [pluck]: struct.Client.html#method.pluck
+    /// [r.args]: struct.r.html#method.args
+    /// [merge]: struct.Client.html#method.merge
+    pub fn without<A>(&self, arg: A) -> Client
+    where
+        A: Into<Arg>,
+    {
+        Client::new(&self.0, 34, arg.into())
+    }
+}
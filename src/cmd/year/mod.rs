@@ -0,0 +1,27 @@
+use crate::Client;
+
+impl Client {
+    /// Return the year of a time object
+    ///
+    /// ## Example
+    ///
+    /// Retrieve all the users born in 1986.
+    ///
+    /// ```rust
+    /// # use reql::{r, Client};
+    /// #
+    /// r.table("users").filter(|user: Client| user.get_field("birthdate").year().eq(1986))
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [month]
+    /// - [day]
+    ///
+    /// [month]: struct.Client.html#method.month
+    /// [day]: struct.Client.html#method.day
+    pub fn year(&self) -> Client {
+        Client::new(&self.0, 128, ())
+    }
+}
@@ -0,0 +1,30 @@
+use crate::Client;
+
+impl Client {
+    /// Merge the `left` and `right` fields of the results of a join into
+    /// single documents
+    ///
+    /// ## Example
+    ///
+    /// Zip down the results of an `eq_join`.
+    ///
+    /// ```rust
+    /// # use reql::r;
+    /// #
+    /// r.table("marvel").eq_join(("main_dc_collaborator", r.table("dc"))).zip()
+    /// # ;
+    /// ```
+    ///
+    /// ## Related commands
+    ///
+    /// - [eq_join]
+    /// - [inner_join]
+    /// - [outer_join]
+    ///
+    /// [eq_join]: struct.Client.html#method.eq_join
+    /// [inner_join]: struct.Client.html#method.inner_join
+    /// [outer_join]: struct.Client.html#method.outer_join
+    pub fn zip(&self) -> Client {
+        Client::new(&self.0, 72, ())
+    }
+}
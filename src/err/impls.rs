@@ -43,6 +43,12 @@ impl From<scram::Error> for Error {
     }
 }
 
+impl From<native_tls::Error> for Error {
+    fn from(err: native_tls::Error) -> Error {
+        Driver::Tls(err).into()
+    }
+}
+
 impl From<str::Utf8Error> for Error {
     fn from(err: str::Utf8Error) -> Error {
         Driver::Utf8(err).into()
@@ -52,6 +52,7 @@ pub enum Driver {
     Auth(String),
     Utf8(str::Utf8Error),
     Scram(scram::Error),
+    Tls(native_tls::Error),
     Io(io::Error),
     Json(js::Error),
     ConnectionBroken,
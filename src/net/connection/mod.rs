@@ -1,42 +1,102 @@
 mod hand_shake;
+mod reader;
+mod stream;
+mod writer;
 
 use {
-    crate::{cmd::connect::Opts, Result},
+    crate::{
+        cmd::{connect::Opts, run::Run},
+        err, Result,
+    },
     bytes::Bytes,
-    futures::{channel::mpsc::UnboundedSender, lock::Mutex},
+    futures::{
+        channel::mpsc::{self, UnboundedSender},
+        prelude::*,
+    },
     hand_shake::HandShake,
-    romio::TcpStream,
-    slab::Slab,
+    reql_types::ServerInfo,
+    serde_json::Value,
     std::{
-        str,
-        sync::atomic::{AtomicBool, Ordering::SeqCst},
+        collections::HashMap,
+        io, str,
+        sync::{
+            atomic::{AtomicBool, Ordering::SeqCst},
+            Arc, Mutex,
+        },
     },
 };
 
-pub(crate) type RequestId = usize;
-pub(crate) type Senders = Mutex<Slab<UnboundedSender<Bytes>>>;
+pub(crate) use stream::Stream;
+
+pub(crate) type RequestId = u64;
+pub(crate) type Senders = Mutex<Slots>;
+
+/// The queries waiting for responses, by token
+///
+/// Tokens are never reused on the same connection. The server may still be
+/// running a `noreply` query, or one we asked to stop, long after we stopped
+/// listening for it, and it rejects tokens that are still in use.
+#[derive(Debug, Default)]
+pub(crate) struct Slots {
+    next: RequestId,
+    slots: HashMap<RequestId, Slot>,
+}
+
+/// What the reader should do with the responses to a query token
+#[derive(Debug)]
+pub(crate) enum Slot {
+    /// Send them to the running query
+    Query(UnboundedSender<Bytes>),
+    /// Discard them, the query was dropped and asked to stop. The slot can be
+    /// freed after this many more responses.
+    Stopping(usize),
+}
+
+/// The length of the header of every message, the query token followed by
+/// the length of the data
+pub(crate) const HEADER_LEN: usize = 8 + 4;
 
 /// The connection object returned by `r.connect()`
+///
+/// Every connection starts two background threads, one writing the queries
+/// and one reading the responses from the server, so connections are not
+/// free to open. Queries can run concurrently on the same connection, which
+/// is usually better than opening one per task.
 #[derive(Debug)]
 pub struct Connection {
     db: String,
-    stream: TcpStream,
+    shared: Arc<Shared>,
+    frames: UnboundedSender<Bytes>,
+}
+
+// The parts of a connection that are shared with its reader
+#[derive(Debug)]
+pub(crate) struct Shared {
+    stream: Stream,
     broken: AtomicBool,
     senders: Senders,
 }
 
 impl Connection {
-    pub(crate) fn new(db: &str, stream: TcpStream) -> Self {
-        Self {
+    pub(crate) fn new(db: &str, stream: Stream) -> io::Result<Self> {
+        let shared = Arc::new(Shared {
             stream,
-            db: db.to_owned(),
             broken: AtomicBool::new(false),
-            senders: Senders::new(Slab::with_capacity(1024)),
-        }
+            senders: Senders::default(),
+        });
+        let (frames, receiver) = mpsc::unbounded();
+        writer::spawn(shared.clone(), receiver)?;
+        Ok(Self {
+            db: db.to_owned(),
+            shared,
+            frames,
+        })
     }
 
     pub(crate) async fn hand_shake<'a>(self, opts: Opts<'a>) -> Result<Self> {
-        await!(HandShake::new(self).greet(opts))
+        let conn = await!(HandShake::new(self).greet(opts))?;
+        reader::spawn(conn.shared.clone())?;
+        Ok(conn)
     }
 
     /// Change the default database on this connection
@@ -56,25 +116,159 @@ impl Connection {
         self.db = name.to_owned();
     }
 
+    /// Wait for all `noreply` queries sent on this connection to be processed
+    /// by the server
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// # #![feature(async_await, await_macro, futures_api)]
+    /// # use futures::executor::block_on;
+    /// # use reql::{r, cmd::run::Opts};
+    /// # use serde_json::{json, Value};
+    /// # fn main() -> reql::Result<()> {
+    /// # block_on(async {
+    /// let conn = await!(r.connect(()))?;
+    /// let opts = Opts::builder().noreply(true).build();
+    /// let query = r.table("events").insert(json!({"type": "click"}));
+    /// await!(query.run::<_, Value>((&conn, opts)))?;
+    /// await!(conn.noreply_wait())?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn noreply_wait(&self) -> impl Future<Output = Result<()>> + '_ {
+        let mut run = Run::<Value>::raw(b"[4]", self);
+        async move {
+            // The server signals completion by ending the stream. Frames are
+            // written in order, so it covers every query queued before it.
+            while let Some(resp) = await!(run.next()) {
+                resp?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Get information about the server this connection is connected to
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// # #![feature(async_await, await_macro, futures_api)]
+    /// # use futures::executor::block_on;
+    /// # use reql::r;
+    /// # fn main() -> reql::Result<()> {
+    /// # block_on(async {
+    /// let conn = await!(r.connect(()))?;
+    /// let server = await!(conn.server())?;
+    /// println!("connected to {:?}", server.name);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn server(&self) -> impl Future<Output = Result<ServerInfo>> + '_ {
+        let run = Run::<ServerInfo>::raw(b"[5]", self);
+        async move {
+            let resp = await!(run)?;
+            match resp.into_value().pop() {
+                Some(info) => Ok(info),
+                None => {
+                    let msg = String::from("server did not send its info");
+                    Err(err::Driver::Other(msg))?
+                }
+            }
+        }
+    }
+
     pub fn broken(&self) -> bool {
-        self.broken.load(SeqCst)
+        self.shared.broken()
     }
 
-    pub(crate) fn stream(&self) -> &TcpStream {
-        &self.stream
+    pub(crate) fn stream(&self) -> &Stream {
+        &self.shared.stream
     }
 
-    pub(crate) fn senders(&self) -> &Senders {
-        &self.senders
+    // Queues a complete frame for the writer
+    pub(crate) fn send(&self, frame: Bytes) -> Result<()> {
+        match self.frames.unbounded_send(frame) {
+            Ok(..) => Ok(()),
+            Err(..) => Err(err::Driver::ConnectionBroken.into()),
+        }
     }
 
-    /*
-    pub(crate) fn mark_broken(&self) {
-        self.broken.store(true, SeqCst);
+    pub(crate) fn senders(&self) -> &Senders {
+        &self.shared.senders
     }
-    */
 
     pub(crate) fn db(&self) -> &str {
         &self.db
     }
 }
+
+impl Slots {
+    // Allocates a token for a query nobody will listen to
+    pub(crate) fn token(&mut self) -> RequestId {
+        let id = self.next;
+        self.next += 1;
+        id
+    }
+
+    pub(crate) fn insert(&mut self, slot: Slot) -> RequestId {
+        let id = self.token();
+        self.slots.insert(id, slot);
+        id
+    }
+
+    pub(crate) fn get_mut(&mut self, id: RequestId) -> Option<&mut Slot> {
+        self.slots.get_mut(&id)
+    }
+
+    pub(crate) fn remove(&mut self, id: RequestId) {
+        self.slots.remove(&id);
+    }
+}
+
+impl Shared {
+    fn broken(&self) -> bool {
+        self.broken.load(SeqCst)
+    }
+
+    // Marks the connection as broken and ends all the queries still waiting
+    // for responses. The senders are closed while holding the lock so no new
+    // query can slip in after the connection is marked as broken.
+    fn mark_broken(&self) {
+        let senders = match self.senders.lock() {
+            Ok(senders) => senders,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        self.broken.store(true, SeqCst);
+        for slot in senders.slots.values() {
+            if let Slot::Query(sender) = slot {
+                sender.close_channel();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cmd::run::Opts, r};
+    use futures::executor::block_on;
+    use serde_json::Value;
+
+    #[test]
+    fn noreply_queries_can_be_waited_for() -> crate::Result<()> {
+        block_on(
+            async {
+                let conn = await!(r.connect(()))?;
+                let opts = Opts::builder().noreply(true).build();
+                let resp = await!(r.expr(1).run::<_, Value>((&conn, opts)))?;
+                assert!(resp.is_empty());
+                await!(conn.noreply_wait())?;
+                let server = await!(conn.server())?;
+                assert!(!server.proxy);
+                Ok(())
+            },
+        )
+    }
+}
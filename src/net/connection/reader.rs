@@ -0,0 +1,81 @@
+use {
+    super::{RequestId, Shared, Slot, HEADER_LEN},
+    crate::Result,
+    bytes::{Buf, Bytes, BytesMut, IntoBuf},
+    futures::{executor::block_on, prelude::*},
+    std::{io, str::from_utf8, sync::Arc, thread},
+};
+
+// Spawns the background reader of a connection
+//
+// The reader is the only one reading from the stream. It reads responses as
+// soon as they arrive and hands them over to the queries they belong to, using
+// the query token to look up their senders. When the connection fails or is
+// dropped, the reader marks it as broken and exits.
+//
+// The reader runs on a thread of its own, blocked on the stream for as long
+// as the connection is open. Futures give us no handle on the executor the
+// connection was created on, so this is the only way to keep reading when no
+// query is being polled. Each open connection therefore costs a thread.
+pub(super) fn spawn(shared: Arc<Shared>) -> io::Result<()> {
+    thread::Builder::new()
+        .name("reql-reader".to_owned())
+        .spawn(move || {
+            if let Err(error) = block_on(read(shared.clone())) {
+                log::debug!("connection reader stopped; error => {:?}", error);
+            }
+            shared.mark_broken();
+        })?;
+    Ok(())
+}
+
+async fn read(shared: Arc<Shared>) -> Result<()> {
+    let mut stream = &shared.stream;
+    let mut header = [0; HEADER_LEN];
+    loop {
+        await!(stream.read_exact(&mut header))?;
+        let mut buf = (&header[..]).into_buf();
+        let id = buf.get_u64_le();
+        let len = buf.get_u32_le() as usize;
+        log::debug!("id => {}; header retrieved; data length => {}", id, len);
+        let mut data = BytesMut::new();
+        data.resize(len, 0);
+        await!(stream.read_exact(&mut data))?;
+        let resp = data.freeze();
+        log::debug!(
+            "id => {}; data retrieved; data => {}",
+            id,
+            from_utf8(&resp).unwrap_or("<invalid utf-8>")
+        );
+        dispatch(&shared, id, resp);
+    }
+}
+
+fn dispatch(shared: &Shared, id: RequestId, resp: Bytes) {
+    let mut senders = match shared.senders.lock() {
+        Ok(senders) => senders,
+        Err(poisoned) => poisoned.into_inner(),
+    };
+    let stopped = match senders.get_mut(id) {
+        Some(Slot::Query(sender)) => {
+            // The receiver is only gone if the query was dropped before this
+            // response arrived, nobody is interested in it anymore
+            if sender.unbounded_send(resp).is_err() {
+                log::debug!("id => {}; receiver dropped, discarding response", id);
+            }
+            false
+        }
+        Some(Slot::Stopping(pending)) => {
+            *pending = pending.saturating_sub(1);
+            *pending == 0
+        }
+        None => {
+            log::debug!("id => {}; no query is waiting, discarding response", id);
+            false
+        }
+    };
+    if stopped {
+        log::debug!("id => {}; query stopped", id);
+        senders.remove(id);
+    }
+}
@@ -0,0 +1,310 @@
+use {
+    crate::{
+        cmd::connect::{Identity as ClientIdentity, Tls},
+        Result,
+    },
+    futures::{
+        future,
+        prelude::*,
+        task::{local_waker_ref_from_nonlocal, Wake},
+        Poll,
+    },
+    native_tls::{
+        Certificate, HandshakeError, Identity, MidHandshakeTlsStream, TlsConnector, TlsStream,
+    },
+    romio::TcpStream,
+    std::{
+        io::{self, Read, Write},
+        mem,
+        net::{IpAddr, Shutdown},
+        sync::{Arc, Mutex, MutexGuard},
+        task::{LocalWaker, Waker},
+    },
+};
+
+const PEM_END: &[u8] = b"-----END CERTIFICATE-----";
+
+/// The stream a connection talks to the server over
+#[derive(Debug)]
+pub(crate) enum Stream {
+    Tcp(TcpStream),
+    // The reader and the writer both need the TLS session, so it's locked
+    // while either of them is using it. It's never locked across polls.
+    Tls(Mutex<TlsStream<Io>>),
+}
+
+// Lets the blocking TLS streams drive a non-blocking socket
+//
+// If the socket is not ready, the tasks using the stream are registered for a
+// wake up and the TLS stream gets a `WouldBlock` error, which we turn back
+// into `Pending`.
+#[derive(Debug)]
+pub(crate) struct Io {
+    stream: TcpStream,
+    tasks: Arc<Tasks>,
+}
+
+// The tasks reading from and writing to the TLS stream
+//
+// A TLS read may need to write to the socket and a TLS write may need to read
+// from it, when the session is renegotiated or its keys are updated. The
+// socket only remembers one waker per direction, so both tasks are woken
+// whenever it becomes ready and whichever of them was waiting tries again.
+#[derive(Debug, Default)]
+struct Tasks {
+    reader: Mutex<Option<Waker>>,
+    writer: Mutex<Option<Waker>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Task {
+    Reader,
+    Writer,
+}
+
+impl Wake for Tasks {
+    fn wake(arc_self: &Arc<Self>) {
+        for task in &[&arc_self.reader, &arc_self.writer] {
+            if let Some(waker) = &*lock(task) {
+                waker.wake();
+            }
+        }
+    }
+}
+
+enum Handshake<'a> {
+    New(TlsConnector, &'a str, Io),
+    Mid(MidHandshakeTlsStream<Io>),
+    Done,
+}
+
+impl Stream {
+    // Negotiates a TLS session on top of a TCP stream
+    pub(crate) fn tls<'a>(
+        stream: TcpStream,
+        tls: Tls<'a>,
+        host: IpAddr,
+    ) -> impl Future<Output = Result<Self>> + 'a {
+        async move {
+            let connector = connector(&tls)?;
+            let host = host.to_string();
+            let domain = tls.domain.unwrap_or(&host);
+            let io = Io {
+                stream,
+                tasks: Default::default(),
+            };
+            let mut handshake = Handshake::New(connector, domain, io);
+            let stream = await!(future::poll_fn(|lw| {
+                let result = match mem::replace(&mut handshake, Handshake::Done) {
+                    Handshake::New(connector, domain, io) => {
+                        io.register(Task::Reader, lw);
+                        connector.connect(domain, io)
+                    }
+                    Handshake::Mid(stream) => {
+                        stream.get_ref().register(Task::Reader, lw);
+                        stream.handshake()
+                    }
+                    Handshake::Done => panic!("TLS handshake polled after completion"),
+                };
+                match result {
+                    Ok(stream) => Poll::Ready(Ok(stream)),
+                    Err(HandshakeError::WouldBlock(stream)) => {
+                        handshake = Handshake::Mid(stream);
+                        Poll::Pending
+                    }
+                    Err(HandshakeError::Failure(error)) => Poll::Ready(Err(error)),
+                }
+            }))?;
+            Ok(Stream::Tls(Mutex::new(stream)))
+        }
+    }
+
+    pub(crate) fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        match self {
+            Stream::Tcp(stream) => stream.shutdown(how),
+            // We don't bother sending a `close_notify` alert since the
+            // server doesn't need it to end the session cleanly
+            Stream::Tls(stream) => lock(stream).get_ref().stream.shutdown(how),
+        }
+    }
+}
+
+impl AsyncRead for &Stream {
+    fn poll_read(&mut self, lw: &LocalWaker, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        match self {
+            Stream::Tcp(stream) => {
+                let mut stream: &TcpStream = stream;
+                stream.poll_read(lw, buf)
+            }
+            Stream::Tls(stream) => poll_tls(stream, Task::Reader, lw, |stream| stream.read(buf)),
+        }
+    }
+}
+
+impl AsyncWrite for &Stream {
+    fn poll_write(&mut self, lw: &LocalWaker, buf: &[u8]) -> Poll<io::Result<usize>> {
+        match self {
+            Stream::Tcp(stream) => {
+                let mut stream: &TcpStream = stream;
+                stream.poll_write(lw, buf)
+            }
+            Stream::Tls(stream) => poll_tls(stream, Task::Writer, lw, |stream| stream.write(buf)),
+        }
+    }
+
+    fn poll_flush(&mut self, lw: &LocalWaker) -> Poll<io::Result<()>> {
+        match self {
+            Stream::Tcp(stream) => {
+                let mut stream: &TcpStream = stream;
+                stream.poll_flush(lw)
+            }
+            Stream::Tls(stream) => poll_tls(stream, Task::Writer, lw, |stream| stream.flush()),
+        }
+    }
+
+    fn poll_close(&mut self, lw: &LocalWaker) -> Poll<io::Result<()>> {
+        match self {
+            Stream::Tcp(stream) => {
+                let mut stream: &TcpStream = stream;
+                stream.poll_close(lw)
+            }
+            Stream::Tls(stream) => poll_tls(stream, Task::Writer, lw, |stream| stream.shutdown()),
+        }
+    }
+}
+
+impl Read for Io {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.poll(|stream, lw| stream.poll_read(lw, buf))
+    }
+}
+
+impl Write for Io {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.poll(|stream, lw| stream.poll_write(lw, buf))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.poll(|stream, lw| stream.poll_flush(lw))
+    }
+}
+
+impl Io {
+    // Remembers which task to wake, local wakers can't be sent to other
+    // threads so it's kept as a `Waker`
+    fn register(&self, task: Task, lw: &LocalWaker) {
+        let mut waker = match task {
+            Task::Reader => lock(&self.tasks.reader),
+            Task::Writer => lock(&self.tasks.writer),
+        };
+        match &*waker {
+            // the same task is usually polled over and over again
+            Some(waker) if lw.will_wake_nonlocal(waker) => {}
+            _ => *waker = Some(lw.clone().into_waker()),
+        }
+    }
+
+    // Polls the socket on behalf of all the tasks using the stream
+    fn poll<F, T>(&mut self, op: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut TcpStream, &LocalWaker) -> Poll<io::Result<T>>,
+    {
+        let lw = local_waker_ref_from_nonlocal(&self.tasks);
+        match op(&mut self.stream, &lw) {
+            Poll::Ready(result) => result,
+            Poll::Pending => Err(io::ErrorKind::WouldBlock.into()),
+        }
+    }
+}
+
+fn poll_tls<F, T>(
+    stream: &Mutex<TlsStream<Io>>,
+    task: Task,
+    lw: &LocalWaker,
+    op: F,
+) -> Poll<io::Result<T>>
+where
+    F: FnOnce(&mut TlsStream<Io>) -> io::Result<T>,
+{
+    let mut stream = lock(stream);
+    stream.get_ref().register(task, lw);
+    match op(&mut *stream) {
+        Err(ref error) if error.kind() == io::ErrorKind::WouldBlock => Poll::Pending,
+        result => Poll::Ready(result),
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+fn connector(tls: &Tls) -> Result<TlsConnector> {
+    let mut builder = TlsConnector::builder();
+    if let Some(bundle) = tls.ca_certs {
+        for pem in pem_certs(bundle) {
+            builder.add_root_certificate(Certificate::from_pem(pem)?);
+        }
+    }
+    match tls.identity {
+        Some(ClientIdentity::Pem(cert, key)) => {
+            builder.identity(Identity::from_pkcs8(cert, key)?);
+        }
+        Some(ClientIdentity::Pkcs12(pkcs12, password)) => {
+            builder.identity(Identity::from_pkcs12(pkcs12, password)?);
+        }
+        None => {}
+    }
+    if tls.insecure {
+        builder
+            .danger_accept_invalid_certs(true)
+            .danger_accept_invalid_hostnames(true);
+    }
+    Ok(builder.build()?)
+}
+
+// Splits a PEM bundle into its certificates since `Certificate::from_pem`
+// only reads the first one
+fn pem_certs(bundle: &[u8]) -> Vec<&[u8]> {
+    let mut certs = Vec::new();
+    let mut rest = bundle;
+    while let Some(pos) = find(rest, PEM_END) {
+        let end = pos + PEM_END.len();
+        certs.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    certs
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{connector, pem_certs};
+    use crate::cmd::connect::Tls;
+
+    #[test]
+    fn pem_bundles_are_split_into_certificates() {
+        let bundle = b"-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n\
+                       -----BEGIN CERTIFICATE-----\nMIIC\n-----END CERTIFICATE-----\n";
+        let certs = pem_certs(bundle);
+        assert_eq!(certs.len(), 2);
+        assert!(certs[1].ends_with(b"MIIC\n-----END CERTIFICATE-----"));
+    }
+
+    #[test]
+    fn client_certificates_need_a_valid_pem_key() {
+        let cert = include_bytes!("../../../tests/tls/client.pem");
+        let key = include_bytes!("../../../tests/tls/client.key");
+        let tls = Tls::builder().cert_and_key(cert, key).build();
+        assert!(connector(&tls).is_ok());
+        let tls = Tls::builder().cert_and_key(cert, b"not a key").build();
+        assert!(connector(&tls).is_err());
+    }
+}
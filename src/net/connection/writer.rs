@@ -0,0 +1,55 @@
+use {
+    super::Shared,
+    crate::Result,
+    bytes::{Bytes, BytesMut},
+    futures::{channel::mpsc::UnboundedReceiver, executor::block_on, prelude::*},
+    std::{io, net::Shutdown, sync::Arc, thread},
+};
+
+// Frames queued at the same time are sent together, up to this many bytes
+const MAX_BATCH_LEN: usize = 64 * 1024;
+
+// Spawns the background writer of a connection
+//
+// The writer is the only one writing to the stream. Queries hand it complete
+// frames, which it writes one after another so they never interleave on the
+// wire. It exits once the connection is dropped and all queued frames are
+// written, or when writing fails. Either way it shuts the stream down, which
+// also stops the reader.
+//
+// Like the reader, the writer runs on a thread of its own, so every open
+// connection costs two threads in total. It sleeps on the channel while no
+// frames are queued.
+pub(super) fn spawn(shared: Arc<Shared>, frames: UnboundedReceiver<Bytes>) -> io::Result<()> {
+    thread::Builder::new()
+        .name("reql-writer".to_owned())
+        .spawn(move || {
+            if let Err(error) = block_on(write(shared.clone(), frames)) {
+                log::debug!("connection writer stopped; error => {:?}", error);
+                shared.mark_broken();
+            }
+            if let Err(error) = shared.stream.shutdown(Shutdown::Both) {
+                log::debug!("failed to shutdown the connection; error => {}", error);
+            }
+        })?;
+    Ok(())
+}
+
+async fn write(shared: Arc<Shared>, mut frames: UnboundedReceiver<Bytes>) -> Result<()> {
+    let mut stream = &shared.stream;
+    let mut buf = BytesMut::new();
+    while let Some(frame) = await!(frames.next()) {
+        buf.extend_from_slice(&frame);
+        // save some syscalls by sending whatever else is already queued
+        while buf.len() < MAX_BATCH_LEN {
+            match frames.try_next() {
+                Ok(Some(frame)) => buf.extend_from_slice(&frame),
+                _ => break,
+            }
+        }
+        log::debug!("sending {} bytes", buf.len());
+        await!(stream.write_all(&buf))?;
+        buf.clear();
+    }
+    Ok(())
+}
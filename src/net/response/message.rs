@@ -41,6 +41,12 @@ pub(crate) enum Message<T> {
     Unexpected(Unexpected),
 }
 
+// Just the type of a response
+#[derive(Deserialize, Debug)]
+struct Type {
+    t: u8,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum SuccessType {
     SuccessAtom,
@@ -79,6 +85,15 @@ impl<T> Message<T> {
     }
 }
 
+// Whether a raw response is a partial sequence, meaning that the server is
+// holding a cursor for the query
+pub(crate) fn is_partial(resp: &[u8]) -> bool {
+    match serde_json::from_slice::<Type>(resp) {
+        Ok(Type { t }) => t == 3,
+        Err(..) => false,
+    }
+}
+
 impl<'de> Deserialize<'de> for SuccessType {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -15,6 +15,10 @@ impl<T> Response<T> {
     pub(crate) fn new(value: Vec<T>, profile: Vec<Profile>) -> Self {
         Self { value, profile }
     }
+
+    pub(crate) fn into_value(self) -> Vec<T> {
+        self.value
+    }
 }
 
 impl<T> Deref for Response<T> {
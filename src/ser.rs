@@ -6,7 +6,7 @@ use {
 
 // Overrides JSON's formatting of arrays to wrap them in the `MAKE_ARRAY`
 // command since ReQL uses arrays for serialization
-struct ReqlFormatter;
+pub(crate) struct ReqlFormatter;
 
 impl Formatter for ReqlFormatter {
     #[inline]
@@ -35,6 +35,27 @@ where
     writer
 }
 
+pub(crate) type ReqlSerializer<'a> = Serializer<&'a mut Vec<u8>, ReqlFormatter>;
+
+// Command options derive their `Serialize` implementation with
+// `#[serde(remote = "Self")]`, which gives them an inherent `serialize`
+// function instead of implementing the trait. If they did implement it,
+// `(arg, opts)` tuples would be serialisable too and would clash with the
+// conversions for plain arguments.
+pub(crate) fn opts_to_vec<F>(serialize: F) -> Vec<u8>
+where
+    F: FnOnce(&mut ReqlSerializer) -> serde_json::Result<()>,
+{
+    let mut writer = Vec::with_capacity(128);
+    serialize(&mut Serializer::with_formatter(&mut writer, ReqlFormatter)).unwrap();
+    // don't include an empty object
+    if writer.len() > 2 {
+        writer
+    } else {
+        Vec::new()
+    }
+}
+
 fn to_writer<W, T: ?Sized>(writer: W, value: &T) -> Result<()>
 where
     W: Write,
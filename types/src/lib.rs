@@ -4,12 +4,16 @@ use std::collections::HashMap;
 use std::net::IpAddr;
 use std::ops::Deref;
 
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use bytes::Bytes;
+use chrono::TimeZone;
+use serde::ser::SerializeStruct;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use uuid::Uuid;
 
+/// A ReQL time, keeping the timezone offset it was created with
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct DateTime(pub chrono::DateTime<chrono::Utc>);
+pub struct DateTime(pub chrono::DateTime<chrono::FixedOffset>);
 
 /// Status returned by a write command
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -26,6 +30,16 @@ pub struct WriteStatus {
     pub changes: Option<Value>,
 }
 
+/// Information about the server a connection is connected to, returned by
+/// `Connection::server`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ServerInfo {
+    pub id: Uuid,
+    /// The name of the server, proxies don't have one
+    pub name: Option<String>,
+    pub proxy: bool,
+}
+
 /// Structure of data in `cluster_config` table
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct ClusterConfig {
@@ -39,7 +53,70 @@ pub struct CurrentIssue {}
 
 /// Structure of data in `db_config` table
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct DbConfig {}
+pub struct DbConfig {
+    pub id: Uuid,
+    pub name: String,
+}
+
+/// Status returned by `db_create`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DbCreateStatus {
+    pub dbs_created: u32,
+    pub config_changes: Vec<Change<DbConfig, DbConfig>>,
+}
+
+/// Status returned by `db_drop`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DbDropStatus {
+    pub dbs_dropped: u32,
+    pub tables_dropped: u32,
+    pub config_changes: Vec<Change<DbConfig, DbConfig>>,
+}
+
+/// Status returned by `table_create`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TableCreateStatus {
+    pub tables_created: u32,
+    pub config_changes: Vec<Change<TableConfig, TableConfig>>,
+}
+
+/// Status returned by `table_drop`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TableDropStatus {
+    pub tables_dropped: u32,
+    pub config_changes: Vec<Change<TableConfig, TableConfig>>,
+}
+
+/// Status returned by `index_create`
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct IndexCreateStatus {
+    pub created: u32,
+}
+
+/// Status returned by `index_drop`
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct IndexDropStatus {
+    pub dropped: u32,
+}
+
+/// Status returned by `index_rename`
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct IndexRenameStatus {
+    pub renamed: u32,
+}
+
+/// Status of a secondary index returned by `index_status` and `index_wait`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, PartialOrd)]
+pub struct IndexStatus {
+    pub index: String,
+    pub ready: bool,
+    pub progress: Option<f64>,
+    pub function: Binary,
+    pub multi: bool,
+    pub geo: bool,
+    pub outdated: bool,
+    pub query: String,
+}
 
 /// Structure of data in `jobs` table
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -96,13 +173,102 @@ pub struct ServerStatus {
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Stat {}
 
-/// Structure of data in `cluster_config` table
+/// Structure of data in `table_config` table, also returned by `config`
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TableConfig {
+    pub id: Uuid,
+    pub name: String,
+    pub db: String,
+    pub primary_key: String,
+    pub shards: Vec<ShardConfig>,
+    pub indexes: Vec<String>,
+    pub write_acks: String,
+    pub durability: String,
+    pub write_hook: Option<WriteHook>,
+}
+
+/// A table's write hook, returned by `get_write_hook`
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct WriteHook {
+    pub function: Binary,
+    pub query: String,
+}
+
+/// Configuration of a single table shard
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ShardConfig {
+    pub primary_replica: String,
+    pub replicas: Vec<String>,
+    pub nonvoting_replicas: Vec<String>,
+}
+
+/// Structure of data in `table_status` table, also returned by `status`
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TableStatus {
+    pub id: Uuid,
+    pub name: String,
+    pub db: String,
+    pub status: TableReadiness,
+    pub raft_leader: Option<String>,
+    pub shards: Option<Vec<ShardStatus>>,
+}
+
+/// Availability of a table
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TableReadiness {
+    pub all_replicas_ready: bool,
+    pub ready_for_outdated_reads: bool,
+    pub ready_for_reads: bool,
+    pub ready_for_writes: bool,
+}
+
+/// Status of a single table shard
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct TableConfig {}
+pub struct ShardStatus {
+    pub primary_replicas: Vec<String>,
+    pub replicas: Vec<ReplicaStatus>,
+}
 
-/// Structure of data in `table_status` table
+/// Status of a single shard replica
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
-pub struct TableStatus {}
+pub struct ReplicaStatus {
+    pub server: String,
+    pub state: String,
+}
+
+/// Status returned by `reconfigure`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReconfigureStatus {
+    pub reconfigured: u32,
+    pub config_changes: Vec<Change<TableConfig, TableConfig>>,
+    pub status_changes: Vec<Change<TableStatus, TableStatus>>,
+}
+
+/// Status returned by `rebalance`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RebalanceStatus {
+    pub rebalanced: u32,
+    pub status_changes: Vec<Change<TableStatus, TableStatus>>,
+}
+
+/// Status returned by `wait`
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct WaitStatus {
+    pub ready: u32,
+}
+
+/// Status returned by `sync`
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SyncStatus {
+    pub synced: u32,
+}
+
+/// Status returned by `grant`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GrantStatus {
+    pub granted: u32,
+    pub permissions_changes: Vec<Change<Value, Value>>,
+}
 
 /// Structure of data in `uses` table
 #[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -119,6 +285,77 @@ pub struct Change<O, N> {
     pub state: Option<String>,
 }
 
+/// The result of `eq_join`, `inner_join` and `outer_join`
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Joined<L, R> {
+    pub left: L,
+    pub right: R,
+}
+
+/// A geometry object of type Point
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Point {
+    pub longitude: f64,
+    pub latitude: f64,
+}
+
+/// A geometry object of type Line
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Line(pub Vec<Point>);
+
+/// A geometry object of type Polygon, made up of its outer ring followed by
+/// any holes punched in it
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Polygon(pub Vec<Vec<Point>>);
+
+/// The result of `match_` when the string matches the regular expression
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Match {
+    pub str: String,
+    pub start: usize,
+    pub end: usize,
+    pub groups: Vec<Option<MatchGroup>>,
+}
+
+/// A capture group of a `Match`, or `None` in `groups` if it didn't match
+#[derive(Debug, Clone, Deserialize, Serialize, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MatchGroup {
+    pub str: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// A ReQL binary object
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Binary(pub Bytes);
+
+/// Grouped data returned by `group`, as a list of key/value pairs
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Grouped<K, V>(pub Vec<(K, V)>);
+
+#[derive(Debug, Deserialize)]
+struct GroupedData<K, V> {
+    #[serde(rename = "$reql_type$")]
+    reql_type: String,
+    data: Vec<(K, V)>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BinaryData {
+    #[serde(rename = "$reql_type$")]
+    reql_type: String,
+    data: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Geometry<C> {
+    #[serde(rename = "$reql_type$")]
+    reql_type: String,
+    #[serde(rename = "type")]
+    kind: String,
+    coordinates: C,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct Time {
     #[serde(rename = "$reql_type$")]
@@ -133,13 +370,26 @@ impl<'de> Deserialize<'de> for DateTime {
         D: Deserializer<'de>,
     {
         let time = Time::deserialize(deserializer)?;
-        let secs = time.epoch_time.trunc() as i64;
-        // RethinkDB timestamps have millisecond precision so we need
-        // to convert the milliseconds to nanoseconds first
-        let msecs = time.epoch_time.fract().abs() as u32;
-        let naive = chrono::NaiveDateTime::from_timestamp(secs, msecs * 1_000_000);
-        let dt = chrono::DateTime::<chrono::Utc>::from_utc(naive, chrono::Utc);
-        Ok(DateTime(dt))
+        let offset = match parse_offset(&time.timezone) {
+            Some(offset) => offset,
+            None => {
+                let unexpected = de::Unexpected::Str(&time.timezone);
+                return Err(de::Error::invalid_value(
+                    unexpected,
+                    &"an offset like +05:30",
+                ));
+            }
+        };
+        // RethinkDB timestamps have millisecond precision so we round to the
+        // nearest millisecond to get rid of floating point noise
+        let millis = (time.epoch_time * 1000.0).round() as i64;
+        match offset.timestamp_millis_opt(millis).single() {
+            Some(dt) => Ok(DateTime(dt)),
+            None => {
+                let unexpected = de::Unexpected::Float(time.epoch_time);
+                Err(de::Error::invalid_value(unexpected, &"a valid timestamp"))
+            }
+        }
     }
 }
 
@@ -149,15 +399,11 @@ impl Serialize for DateTime {
         S: Serializer,
     {
         let reql_type = String::from("TIME");
-        let epoch_time = {
-            let t = format!(
-                "{}.{}",
-                self.0.timestamp(),
-                self.0.timestamp_subsec_millis()
-            );
-            t.parse().unwrap()
-        };
-        let timezone = String::from("+00:00");
+        let epoch_time = self.0.timestamp_millis() as f64 / 1000.0;
+        let offset = self.0.offset().local_minus_utc();
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.abs();
+        let timezone = format!("{}{:02}:{:02}", sign, offset / 3600, offset % 3600 / 60);
         let time = Time {
             reql_type,
             epoch_time,
@@ -167,10 +413,415 @@ impl Serialize for DateTime {
     }
 }
 
+// Parses offsets in the `[+-]HH:MM` format used by RethinkDB
+fn parse_offset(timezone: &str) -> Option<chrono::FixedOffset> {
+    if timezone == "Z" {
+        return chrono::FixedOffset::east_opt(0);
+    }
+    let sign = match timezone.get(..1)? {
+        "+" => 1,
+        "-" => -1,
+        _ => return None,
+    };
+    let mut parts = timezone[1..].splitn(2, ':');
+    let hours: i32 = parts.next()?.parse().ok()?;
+    let minutes: i32 = parts.next().unwrap_or("0").parse().ok()?;
+    chrono::FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+impl<'de> Deserialize<'de> for Binary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let binary = BinaryData::deserialize(deserializer)?;
+        if binary.reql_type != "BINARY" {
+            let unexpected = de::Unexpected::Str(&binary.reql_type);
+            return Err(de::Error::invalid_value(unexpected, &"BINARY"));
+        }
+        match base64::decode(&binary.data) {
+            Ok(data) => Ok(Binary(data.into())),
+            Err(_) => {
+                let unexpected = de::Unexpected::Str(&binary.data);
+                Err(de::Error::invalid_value(unexpected, &"base64 encoded data"))
+            }
+        }
+    }
+}
+
+impl Serialize for Binary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let binary = BinaryData {
+            reql_type: String::from("BINARY"),
+            data: base64::encode(&self.0),
+        };
+        binary.serialize(serializer)
+    }
+}
+
+impl<C> Geometry<C> {
+    fn new(kind: &str, coordinates: C) -> Self {
+        Geometry {
+            reql_type: String::from("GEOMETRY"),
+            kind: kind.to_owned(),
+            coordinates,
+        }
+    }
+
+    fn coordinates<'de, D>(kind: &'static str, deserializer: D) -> Result<C, D::Error>
+    where
+        C: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let geometry = Geometry::<C>::deserialize(deserializer)?;
+        if geometry.reql_type != "GEOMETRY" {
+            let unexpected = de::Unexpected::Str(&geometry.reql_type);
+            return Err(de::Error::invalid_value(unexpected, &"GEOMETRY"));
+        }
+        if geometry.kind != kind {
+            let unexpected = de::Unexpected::Str(&geometry.kind);
+            return Err(de::Error::invalid_value(unexpected, &kind));
+        }
+        Ok(geometry.coordinates)
+    }
+}
+
+// GeoJSON coordinates are `[longitude, latitude]` pairs
+type Coordinates = (f64, f64);
+
+impl From<Coordinates> for Point {
+    fn from((longitude, latitude): Coordinates) -> Self {
+        Point {
+            longitude,
+            latitude,
+        }
+    }
+}
+
+impl Point {
+    fn coordinates(&self) -> Coordinates {
+        (self.longitude, self.latitude)
+    }
+}
+
+impl<'de> Deserialize<'de> for Point {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let coordinates = Geometry::<Coordinates>::coordinates("Point", deserializer)?;
+        Ok(coordinates.into())
+    }
+}
+
+impl Serialize for Point {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Geometry::new("Point", self.coordinates()).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Line {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let coordinates = Geometry::<Vec<Coordinates>>::coordinates("LineString", deserializer)?;
+        Ok(Line(coordinates.into_iter().map(Point::from).collect()))
+    }
+}
+
+impl Serialize for Line {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let coordinates: Vec<_> = self.0.iter().map(Point::coordinates).collect();
+        Geometry::new("LineString", coordinates).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Polygon {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let rings = Geometry::<Vec<Vec<Coordinates>>>::coordinates("Polygon", deserializer)?;
+        let rings = rings
+            .into_iter()
+            .map(|ring| ring.into_iter().map(Point::from).collect())
+            .collect();
+        Ok(Polygon(rings))
+    }
+}
+
+impl Serialize for Polygon {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let rings: Vec<Vec<_>> = self
+            .0
+            .iter()
+            .map(|ring| ring.iter().map(Point::coordinates).collect())
+            .collect();
+        Geometry::new("Polygon", rings).serialize(serializer)
+    }
+}
+
+impl<'de, K, V> Deserialize<'de> for Grouped<K, V>
+where
+    K: Deserialize<'de>,
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let grouped = GroupedData::deserialize(deserializer)?;
+        if grouped.reql_type != "GROUPED_DATA" {
+            let unexpected = de::Unexpected::Str(&grouped.reql_type);
+            return Err(de::Error::invalid_value(unexpected, &"GROUPED_DATA"));
+        }
+        Ok(Grouped(grouped.data))
+    }
+}
+
+impl<K, V> Serialize for Grouped<K, V>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut grouped = serializer.serialize_struct("GroupedData", 2)?;
+        grouped.serialize_field("$reql_type$", "GROUPED_DATA")?;
+        grouped.serialize_field("data", &self.0)?;
+        grouped.end()
+    }
+}
+
+impl<K, V> Deref for Grouped<K, V> {
+    type Target = Vec<(K, V)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
 impl Deref for DateTime {
-    type Target = chrono::DateTime<chrono::Utc>;
+    type Target = chrono::DateTime<chrono::FixedOffset>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Deref for Binary {
+    type Target = Bytes;
 
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
+
+impl From<Bytes> for Binary {
+    fn from(bytes: Bytes) -> Self {
+        Binary(bytes)
+    }
+}
+
+impl From<Vec<u8>> for Binary {
+    fn from(bytes: Vec<u8>) -> Self {
+        Binary(bytes.into())
+    }
+}
+
+impl<'a> From<&'a [u8]> for Binary {
+    fn from(bytes: &'a [u8]) -> Self {
+        Binary(Bytes::from(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, serde_json::json};
+
+    fn time(epoch_time: f64, timezone: &str) -> Value {
+        json!({
+            "$reql_type$": "TIME",
+            "epoch_time": epoch_time,
+            "timezone": timezone,
+        })
+    }
+
+    #[test]
+    fn times_keep_their_offset() {
+        for &timezone in &["+05:30", "-08:00", "+00:00"] {
+            let dt: DateTime = serde_json::from_value(time(1_500_000_000.0, timezone)).unwrap();
+            assert_eq!(dt.timestamp(), 1_500_000_000);
+            assert_eq!(dt.offset().to_string(), timezone);
+            let value = serde_json::to_value(&dt).unwrap();
+            assert_eq!(value, time(1_500_000_000.0, timezone));
+        }
+    }
+
+    #[test]
+    fn utc_times_can_use_z_as_their_offset() {
+        let dt: DateTime = serde_json::from_value(time(0.0, "Z")).unwrap();
+        assert_eq!(dt.offset().local_minus_utc(), 0);
+        let value = serde_json::to_value(&dt).unwrap();
+        assert_eq!(value, time(0.0, "+00:00"));
+    }
+
+    #[test]
+    fn invalid_offsets_are_rejected() {
+        for &timezone in &["05:30", "+aa:00", ""] {
+            let value = time(0.0, timezone);
+            assert!(serde_json::from_value::<DateTime>(value).is_err());
+        }
+    }
+
+    #[test]
+    fn times_are_rounded_to_the_millisecond() {
+        let dt: DateTime = serde_json::from_value(time(12.3456, "+00:00")).unwrap();
+        assert_eq!(dt.timestamp(), 12);
+        assert_eq!(dt.timestamp_subsec_nanos(), 346_000_000);
+        let dt: DateTime = serde_json::from_value(time(0.0004, "+00:00")).unwrap();
+        assert_eq!(dt.timestamp_subsec_nanos(), 0);
+        // floating point noise doesn't survive a round trip either
+        let dt: DateTime = serde_json::from_value(time(0.1 + 0.2, "+00:00")).unwrap();
+        let value = serde_json::to_value(&dt).unwrap();
+        assert_eq!(value, time(0.3, "+00:00"));
+    }
+
+    #[test]
+    fn points_round_trip_through_geojson() {
+        let value = json!({
+            "$reql_type$": "GEOMETRY",
+            "type": "Point",
+            "coordinates": [-122.423246, 37.779388],
+        });
+        let point: Point = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(point.longitude, -122.423246);
+        assert_eq!(point.latitude, 37.779388);
+        assert_eq!(serde_json::to_value(point).unwrap(), value);
+    }
+
+    #[test]
+    fn lines_round_trip_through_geojson() {
+        let value = json!({
+            "$reql_type$": "GEOMETRY",
+            "type": "LineString",
+            "coordinates": [[-122.4, 37.7], [-121.8, 37.8], [-121.4, 37.2]],
+        });
+        let line: Line = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(line.0.len(), 3);
+        assert_eq!(line.0[1], Point::from((-121.8, 37.8)));
+        assert_eq!(serde_json::to_value(&line).unwrap(), value);
+    }
+
+    #[test]
+    fn polygons_round_trip_through_geojson() {
+        let value = json!({
+            "$reql_type$": "GEOMETRY",
+            "type": "Polygon",
+            "coordinates": [
+                [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0], [0.0, 0.0]],
+                [[2.0, 2.0], [4.0, 2.0], [4.0, 4.0], [2.0, 4.0], [2.0, 2.0]],
+            ],
+        });
+        let polygon: Polygon = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(polygon.0.len(), 2);
+        assert_eq!(polygon.0[1][2], Point::from((4.0, 4.0)));
+        assert_eq!(serde_json::to_value(&polygon).unwrap(), value);
+    }
+
+    #[test]
+    fn geometry_of_another_type_is_rejected() {
+        let value = json!({
+            "$reql_type$": "GEOMETRY",
+            "type": "LineString",
+            "coordinates": [[0.0, 0.0], [1.0, 1.0]],
+        });
+        assert!(serde_json::from_value::<Point>(value.clone()).is_err());
+        assert!(serde_json::from_value::<Polygon>(value).is_err());
+        let value = json!({"$reql_type$": "TIME", "type": "Point", "coordinates": [0.0, 0.0]});
+        assert!(serde_json::from_value::<Point>(value).is_err());
+    }
+
+    fn binary(data: &str) -> Value {
+        json!({"$reql_type$": "BINARY", "data": data})
+    }
+
+    #[test]
+    fn binaries_round_trip_through_base64() {
+        let cases: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"hello", "aGVsbG8="),
+            (&[0, 159, 146, 150, 255], "AJ+Slv8="),
+        ];
+        for &(bytes, data) in cases {
+            let value = serde_json::to_value(Binary::from(bytes)).unwrap();
+            assert_eq!(value, binary(data));
+            let decoded: Binary = serde_json::from_value(value).unwrap();
+            assert_eq!(&decoded[..], bytes);
+        }
+    }
+
+    #[test]
+    fn invalid_binaries_are_rejected() {
+        assert!(serde_json::from_value::<Binary>(binary("not base64!")).is_err());
+        let value = json!({"$reql_type$": "TIME", "data": ""});
+        assert!(serde_json::from_value::<Binary>(value).is_err());
+    }
+
+    #[test]
+    fn index_functions_are_decoded_as_binaries() {
+        let value = json!({
+            "index": "code_name",
+            "ready": true,
+            "function": binary("aGVsbG8="),
+            "multi": false,
+            "geo": false,
+            "outdated": false,
+            "query": "indexCreate('code_name', function(var1) { return var1('code_name'); })",
+        });
+        let status: IndexStatus = serde_json::from_value(value).unwrap();
+        assert_eq!(&status.function[..], b"hello");
+        assert_eq!(status.progress, None);
+    }
+
+    #[test]
+    fn grouped_reductions_are_decoded_into_pairs() {
+        let data = json!({
+            "$reql_type$": "GROUPED_DATA",
+            "data": [["Alice", 3], ["Bob", 5]],
+        });
+        let grouped: Grouped<String, u32> = serde_json::from_value(data).unwrap();
+        let expected = vec![(String::from("Alice"), 3), (String::from("Bob"), 5)];
+        assert_eq!(*grouped, expected);
+        // keys of groups made with more than one field are arrays
+        let data = json!({
+            "$reql_type$": "GROUPED_DATA",
+            "data": [[["Alice", 2019], 1.5]],
+        });
+        let grouped: Grouped<(String, u16), f64> = serde_json::from_value(data).unwrap();
+        assert_eq!(grouped[0], ((String::from("Alice"), 2019), 1.5));
+        let value = serde_json::to_value(&grouped).unwrap();
+        assert_eq!(value["$reql_type$"], "GROUPED_DATA");
+    }
+
+    #[test]
+    fn other_pseudo_types_are_not_grouped_data() {
+        let data = json!({"$reql_type$": "BINARY", "data": []});
+        assert!(serde_json::from_value::<Grouped<String, u32>>(data).is_err());
+    }
+}